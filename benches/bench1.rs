@@ -2,6 +2,7 @@
 extern crate test;
 extern crate itertools;
 
+use itertools::Itertools;
 use itertools::Stride;
 
 #[cfg(not(feature = "unstable"))]
@@ -224,3 +225,246 @@ fn zip_loop3(b: &mut test::Bencher)
         }
     })
 }
+
+fn kmerge_runs(k: usize, total: usize) -> Vec<Vec<u32>>
+{
+    let mut runs = vec![Vec::with_capacity(total / k); k];
+    for i in 0..total {
+        runs[i % k].push(i as u32);
+    }
+    runs
+}
+
+#[bench]
+fn kmerge_2(b: &mut test::Bencher)
+{
+    let runs = kmerge_runs(2, 4096);
+    b.iter(|| for elt in runs.iter().map(|v| v.iter()).kmerge() {
+        test::black_box(elt);
+    })
+}
+
+#[bench]
+fn kmerge_16(b: &mut test::Bencher)
+{
+    let runs = kmerge_runs(16, 4096);
+    b.iter(|| for elt in runs.iter().map(|v| v.iter()).kmerge() {
+        test::black_box(elt);
+    })
+}
+
+#[bench]
+fn kmerge_256(b: &mut test::Bencher)
+{
+    let runs = kmerge_runs(256, 4096);
+    b.iter(|| for elt in runs.iter().map(|v| v.iter()).kmerge() {
+        test::black_box(elt);
+    })
+}
+
+#[bench]
+fn cartesian_product_iterator(b: &mut test::Bencher)
+{
+    let xs = vec![0; 16];
+
+    b.iter(|| {
+        let mut sum = 0;
+        for (&x, &y) in xs.iter().cartesian_product(&xs) {
+            sum += x;
+            sum += y;
+        }
+        sum
+    })
+}
+
+#[bench]
+fn cartesian_product_fold(b: &mut test::Bencher)
+{
+    let xs = vec![0; 16];
+
+    b.iter(|| {
+        let mut sum = 0;
+        xs.iter().cartesian_product(&xs).fold((), |(), (&x, &y)| {
+            sum += x;
+            sum += y;
+        });
+        sum
+    })
+}
+
+#[bench]
+fn cartesian_product_nested_for(b: &mut test::Bencher)
+{
+    let xs = vec![0; 16];
+
+    b.iter(|| {
+        let mut sum = 0;
+        for &x in &xs {
+            for &y in &xs {
+                sum += x;
+                sum += y;
+            }
+        }
+        sum
+    })
+}
+
+/// 1M pseudo-random u64s from a xorshift generator.
+fn random_u64s() -> Vec<u64>
+{
+    itertools::iterate(0x2545F4914F6CDD1Du64, |&x| {
+        let x = x ^ (x << 13);
+        let x = x ^ (x >> 7);
+        x ^ (x << 17)
+    }).take(1_000_000).collect()
+}
+
+#[bench]
+fn sorted_1m(b: &mut test::Bencher)
+{
+    let data = random_u64s();
+    b.iter(|| {
+        test::black_box(data.iter().cloned().sorted().last())
+    })
+}
+
+#[bench]
+fn sorted_unstable_1m(b: &mut test::Bencher)
+{
+    let data = random_u64s();
+    b.iter(|| {
+        test::black_box(data.iter().cloned().sorted_unstable().last())
+    })
+}
+
+/// 10M integers in runs of consecutive values.
+fn consecutive_runs() -> Vec<u32>
+{
+    (0..10_000_000u32).map(|i| i + (i / 64) * 3).collect()
+}
+
+fn merge_consecutive(a: (u32, u32), b: (u32, u32)) -> Result<(u32, u32), ((u32, u32), (u32, u32))>
+{
+    if a.1 + 1 == b.0 { Ok((a.0, b.1)) } else { Err((a, b)) }
+}
+
+#[bench]
+fn coalesce_runs_next(b: &mut test::Bencher)
+{
+    let data = consecutive_runs();
+    b.iter(|| {
+        let mut count = 0;
+        for run in data.iter().map(|&x| (x, x)).coalesce(merge_consecutive) {
+            count += (run.1 - run.0) as usize;
+        }
+        test::black_box(count)
+    })
+}
+
+#[bench]
+fn coalesce_runs_fold(b: &mut test::Bencher)
+{
+    let data = consecutive_runs();
+    b.iter(|| {
+        let count = data.iter().map(|&x| (x, x)).coalesce(merge_consecutive)
+                        .fold(0, |count, run| count + (run.1 - run.0) as usize);
+        test::black_box(count)
+    })
+}
+
+#[bench]
+fn coalesce_chained_runs_next(b: &mut test::Bencher)
+{
+    let data = consecutive_runs();
+    let (front, back) = data.split_at(data.len() / 2);
+    b.iter(|| {
+        let mut count = 0;
+        let runs = front.iter().chain(back).map(|&x| (x, x)).coalesce(merge_consecutive);
+        for run in runs {
+            count += (run.1 - run.0) as usize;
+        }
+        test::black_box(count)
+    })
+}
+
+#[bench]
+fn coalesce_chained_runs_fold(b: &mut test::Bencher)
+{
+    let data = consecutive_runs();
+    let (front, back) = data.split_at(data.len() / 2);
+    b.iter(|| {
+        let count = front.iter().chain(back).map(|&x| (x, x)).coalesce(merge_consecutive)
+                         .fold(0, |count, run| count + (run.1 - run.0) as usize);
+        test::black_box(count)
+    })
+}
+
+#[bench]
+fn coalesce_singletons_next(b: &mut test::Bencher)
+{
+    let data = (0..10_000_000u32).map(|i| i * 2).collect_vec();
+    b.iter(|| {
+        let mut count = 0;
+        for run in data.iter().map(|&x| (x, x)).coalesce(merge_consecutive) {
+            count += (run.1 - run.0) as usize;
+        }
+        test::black_box(count)
+    })
+}
+
+#[bench]
+fn coalesce_singletons_fold(b: &mut test::Bencher)
+{
+    let data = (0..10_000_000u32).map(|i| i * 2).collect_vec();
+    b.iter(|| {
+        let count = data.iter().map(|&x| (x, x)).coalesce(merge_consecutive)
+                        .fold(0, |count, run| count + (run.1 - run.0) as usize);
+        test::black_box(count)
+    })
+}
+
+#[bench]
+fn dedup_by_key_runs_next(b: &mut test::Bencher)
+{
+    let data = consecutive_runs();
+    b.iter(|| {
+        let mut count = 0;
+        for x in data.iter().dedup_by_key(|&&x| x / 16) {
+            count += *x as usize;
+        }
+        test::black_box(count)
+    })
+}
+
+#[bench]
+fn dedup_by_key_runs_fold(b: &mut test::Bencher)
+{
+    let data = consecutive_runs();
+    b.iter(|| {
+        let count = data.iter().dedup_by_key(|&&x| x / 16).fold(0, |count, x| count + *x as usize);
+        test::black_box(count)
+    })
+}
+
+#[bench]
+fn dedup_by_key_singletons_next(b: &mut test::Bencher)
+{
+    let data = consecutive_runs();
+    b.iter(|| {
+        let mut count = 0;
+        for x in data.iter().dedup_by_key(|&&x| x) {
+            count += *x as usize;
+        }
+        test::black_box(count)
+    })
+}
+
+#[bench]
+fn dedup_by_key_singletons_fold(b: &mut test::Bencher)
+{
+    let data = consecutive_runs();
+    b.iter(|| {
+        let count = data.iter().dedup_by_key(|&&x| x).fold(0, |count, x| count + *x as usize);
+        test::black_box(count)
+    })
+}
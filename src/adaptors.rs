@@ -4,15 +4,20 @@
 //! option. This file may not be copied, modified, or distributed
 //! except according to those terms.
 
-use std::mem;
-#[cfg(feature = "unstable")]
-use std::num::One;
-#[cfg(feature = "unstable")]
-use std::ops::Add;
-use std::cmp::Ordering;
-use std::iter::{Fuse, Peekable};
+#[cfg(feature = "use_std")]
+use core::mem;
+use core::marker::PhantomData;
+use core::ops::Range;
+use core::cmp::Ordering;
+use core::iter::{Fuse, FusedIterator};
+#[cfg(feature = "use_std")]
+use std::collections::VecDeque;
+#[cfg(feature = "use_std")]
+use std::vec::Vec;
 use Itertools;
 use size_hint;
+#[cfg(feature = "use_std")]
+use PeekingNext;
 
 macro_rules! clone_fields {
     ($name:ident, $base:expr, $($field:ident),+) => (
@@ -124,229 +129,1246 @@ impl<B, I> Clone for FnMap<B, I> where
     }
 }
 
+/// An iterator adaptor that converts each element using **Into**.
+///
+/// Iterator element type is **R**.
+pub struct MapInto<I, R> {
+    iter: I,
+    _res: PhantomData<R>,
+}
+
+impl<I, R> MapInto<I, R> where
+    I: Iterator,
+    I::Item: Into<R>,
+{
+    /// Create a new **MapInto**.
+    pub fn new(iter: I) -> Self
+    {
+        MapInto{iter: iter, _res: PhantomData}
+    }
+}
+
+impl<I, R> Iterator for MapInto<I, R> where
+    I: Iterator,
+    I::Item: Into<R>,
+{
+    type Item = R;
+    #[inline]
+    fn next(&mut self) -> Option<R>
+    {
+        self.iter.next().map(Into::into)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, R> DoubleEndedIterator for MapInto<I, R> where
+    I: DoubleEndedIterator,
+    I::Item: Into<R>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<R> {
+        self.iter.next_back().map(Into::into)
+    }
+}
+
+// same size
+impl<I, R> ExactSizeIterator for MapInto<I, R> where
+    I: ExactSizeIterator,
+    I::Item: Into<R>,
+{ }
+
+impl<I, R> Clone for MapInto<I, R> where
+    I: Clone,
+{
+    fn clone(&self) -> Self
+    {
+        MapInto{iter: self.iter.clone(), _res: PhantomData}
+    }
+}
+
+/// An iterator adaptor that yields the index ranges of the maximal runs of
+/// consecutive elements for which the predicate returns **true**.
+///
+/// See [*.position_runs()*](trait.Itertools.html#method.position_runs) for more information.
+#[derive(Clone)]
+pub struct PositionRuns<I, F> {
+    iter: Fuse<I>,
+    f: F,
+    count: usize,
+}
+
+impl<I, F> PositionRuns<I, F> where
+    I: Iterator,
+{
+    /// Create a new **PositionRuns**.
+    pub fn new(iter: I, f: F) -> Self
+    {
+        PositionRuns{iter: iter.fuse(), f: f, count: 0}
+    }
+}
+
+impl<I, F> Iterator for PositionRuns<I, F> where
+    I: Iterator,
+    F: FnMut(I::Item) -> bool,
+{
+    type Item = Range<usize>;
+
+    fn next(&mut self) -> Option<Range<usize>>
+    {
+        let mut start = None;
+        while let Some(v) = self.iter.next() {
+            let i = self.count;
+            self.count = i + 1;
+            if (self.f)(v) {
+                if start.is_none() {
+                    start = Some(i);
+                }
+            } else if let Some(start) = start {
+                return Some(start..i);
+            }
+        }
+        start.map(|start| start..self.count)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        // runs are separated by at least one element
+        let (_, hi) = self.iter.size_hint();
+        (0, hi.map(|hi| hi / 2 + hi % 2))
+    }
+}
+
+impl<I, F> FusedIterator for PositionRuns<I, F> where
+    I: Iterator,
+    F: FnMut(I::Item) -> bool,
+{ }
+
+/// An iterator adaptor that yields the indices of the elements for which
+/// the predicate returns **true**.
+///
+/// See [*.positions()*](trait.Itertools.html#method.positions) for more information.
+#[derive(Clone)]
+pub struct Positions<I, F> {
+    iter: I,
+    f: F,
+    count: usize,
+}
+
+impl<I, F> Positions<I, F> {
+    /// Create a new **Positions**.
+    pub fn new(iter: I, f: F) -> Self
+    {
+        Positions{iter: iter, f: f, count: 0}
+    }
+}
+
+impl<I, F> Iterator for Positions<I, F> where
+    I: Iterator,
+    F: FnMut(I::Item) -> bool,
+{
+    type Item = usize;
+
+    fn next(&mut self) -> Option<usize>
+    {
+        while let Some(v) = self.iter.next() {
+            let i = self.count;
+            self.count = i + 1;
+            if (self.f)(v) {
+                return Some(i);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, F> DoubleEndedIterator for Positions<I, F> where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    F: FnMut(I::Item) -> bool,
+{
+    fn next_back(&mut self) -> Option<usize>
+    {
+        // The index of an element taken from the back is the number of
+        // elements consumed from the front plus what remains before it
+        while let Some(v) = self.iter.next_back() {
+            if (self.f)(v) {
+                return Some(self.count + self.iter.len());
+            }
+        }
+        None
+    }
+}
+
+/// An iterator adaptor that maps each **Ok** value of an iterator of
+/// **Result**s to an iterable and flattens the results, passing **Err**
+/// values through.
+///
+/// See [*.flat_map_ok()*](trait.Itertools.html#method.flat_map_ok) for more information.
+///
+/// Iterator element type is **Result\<U::Item, E\>**.
+pub struct FlatMapOk<I, F, U> where
+    U: IntoIterator,
+{
+    iter: I,
+    f: F,
+    inner: Option<U::IntoIter>,
+}
+
+impl<I, F, U> FlatMapOk<I, F, U> where
+    U: IntoIterator,
+{
+    /// Create a new **FlatMapOk**.
+    pub fn new(iter: I, f: F) -> Self
+    {
+        FlatMapOk{iter: iter, f: f, inner: None}
+    }
+}
+
+impl<I, F, U> Clone for FlatMapOk<I, F, U> where
+    I: Clone,
+    F: Clone,
+    U: IntoIterator,
+    U::IntoIter: Clone,
+{
+    fn clone(&self) -> Self
+    {
+        clone_fields!(FlatMapOk, self, iter, f, inner)
+    }
+}
+
+impl<I, F, T, E, U> Iterator for FlatMapOk<I, F, U> where
+    I: Iterator<Item=Result<T, E>>,
+    F: FnMut(T) -> U,
+    U: IntoIterator,
+{
+    type Item = Result<U::Item, E>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        loop {
+            if let Some(ref mut inner) = self.inner {
+                if let Some(elt) = inner.next() {
+                    return Some(Ok(elt));
+                }
+            }
+            self.inner = None;
+            match self.iter.next() {
+                Some(Ok(v)) => self.inner = Some((self.f)(v).into_iter()),
+                Some(Err(e)) => return Some(Err(e)),
+                None => return None,
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let inner = match self.inner {
+            Some(ref inner) => inner.size_hint(),
+            None => (0, Some(0)),
+        };
+        match self.iter.size_hint() {
+            (_, Some(0)) => inner,
+            _ => (inner.0, None),
+        }
+    }
+}
+
+/// An iterator adaptor that filters and maps the **Ok** values of an
+/// iterator of **Result**s, passing **Err** values through.
+///
+/// See [*.filter_map_ok()*](trait.Itertools.html#method.filter_map_ok) for more information.
+///
+/// Iterator element type is **Result\<U, E\>**.
+#[derive(Clone)]
+pub struct FilterMapOk<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I, F> FilterMapOk<I, F> {
+    /// Create a new **FilterMapOk**.
+    pub fn new(iter: I, f: F) -> Self
+    {
+        FilterMapOk{iter: iter, f: f}
+    }
+}
+
+impl<I, F, T, E, U> Iterator for FilterMapOk<I, F> where
+    I: Iterator<Item=Result<T, E>>,
+    F: FnMut(T) -> Option<U>,
+{
+    type Item = Result<U, E>;
+
+    fn next(&mut self) -> Option<Result<U, E>>
+    {
+        while let Some(elt) = self.iter.next() {
+            match elt {
+                Ok(v) => if let Some(u) = (self.f)(v) {
+                    return Some(Ok(u));
+                },
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I, F, T, E, U> DoubleEndedIterator for FilterMapOk<I, F> where
+    I: DoubleEndedIterator<Item=Result<T, E>>,
+    F: FnMut(T) -> Option<U>,
+{
+    fn next_back(&mut self) -> Option<Result<U, E>>
+    {
+        while let Some(elt) = self.iter.next_back() {
+            match elt {
+                Ok(v) => if let Some(u) = (self.f)(v) {
+                    return Some(Ok(u));
+                },
+                Err(e) => return Some(Err(e)),
+            }
+        }
+        None
+    }
+}
+
 #[derive(Clone)]
 /// An iterator adaptor that allows putting back a single
 /// item to the front of the iterator.
 ///
-/// Iterator element type is **I::Item**.
-pub struct PutBack<I> where
+/// Iterator element type is **I::Item**.
+pub struct PutBack<I> where
+    I: Iterator,
+{
+    top: Option<I::Item>,
+    iter: I,
+}
+
+impl<I> PutBack<I> where
+    I: Iterator,
+{
+    /// Iterator element type is `A`
+    #[inline]
+    pub fn new(it: I) -> Self
+    {
+        PutBack{top: None, iter: it}
+    }
+
+    /// Put back a single value to the front of the iterator.
+    ///
+    /// If a value is already in the put back slot, it is overwritten.
+    #[inline]
+    pub fn put_back(&mut self, x: I::Item)
+    {
+        self.top = Some(x)
+    }
+
+    /// Return a reference to the next element, pulling it into the put
+    /// back slot if needed.
+    fn peek(&mut self) -> Option<&I::Item>
+    {
+        if self.top.is_none() {
+            self.top = self.iter.next();
+        }
+        self.top.as_ref()
+    }
+}
+
+impl<I> Iterator for PutBack<I> where
+    I: Iterator,
+{
+    type Item = I::Item;
+    #[inline]
+    fn next(&mut self) -> Option<I::Item> {
+        match self.top {
+            None => self.iter.next(),
+            ref mut some => some.take(),
+        }
+    }
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint::add_scalar(self.iter.size_hint(), self.top.is_some() as usize)
+    }
+}
+
+/// The put back element is in front of the iterator, so it is yielded last
+/// from the back.
+impl<I> DoubleEndedIterator for PutBack<I> where
+    I: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item> {
+        match self.iter.next_back() {
+            None => self.top.take(),
+            some => some,
+        }
+    }
+}
+
+/// The length is that of the base iterator plus the put back element. If
+/// that overflows **usize**, the size hint saturates to
+/// **(usize::MAX, None)** and *.len()* panics.
+impl<I> ExactSizeIterator for PutBack<I> where
+    I: ExactSizeIterator,
+{ }
+
+#[derive(Clone)]
+/// An iterator adaptor that iterates over the cartesian product of
+/// the element sets of two iterators **I** and **J**.
+///
+/// Iterator element type is **(I::Item, J::Item)**.
+pub struct Product<I, J> where
+    I: Iterator,
+{
+    a: I,
+    a_cur: Option<I::Item>,
+    b: J,
+    b_orig: J,
+    /// The row started from the back, with what remains of it; the rows
+    /// still in **a** are between it and the front row.
+    back: Option<(I::Item, J)>,
+}
+
+impl<I, J> Product<I, J> where
+    I: Iterator,
+    J: Clone + Iterator,
+    I::Item: Clone,
+{
+    /// Create a new cartesian product iterator
+    ///
+    /// Iterator element type is **(I::Item, J::Item)**.
+    pub fn new(i: I, j: J) -> Self
+    {
+        let mut i = i;
+        Product{a_cur: i.next(), a: i, b: j.clone(), b_orig: j, back: None}
+    }
+}
+
+
+impl<I, J> Iterator for Product<I, J> where
+    I: Iterator,
+    J: Clone + Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, J::Item);
+    fn next(&mut self) -> Option<(I::Item, J::Item)>
+    {
+        if let Some(elt_b) = self.b.next() {
+            return self.a_cur.as_ref().map(|a| (a.clone(), elt_b));
+        }
+        let mut b = self.b_orig.clone();
+        let elt_b = match b.next() {
+            None => return None,
+            Some(x) => x,
+        };
+        match self.a.next() {
+            Some(a) => {
+                self.b = b;
+                self.a_cur = Some(a);
+                self.a_cur.as_ref().map(|a| (a.clone(), elt_b))
+            }
+            None => {
+                // The only row left is the one started from the back
+                match self.back.take() {
+                    None => {
+                        self.a_cur = None;
+                        None
+                    }
+                    Some((a, mut b_back)) => {
+                        let elt = b_back.next().map(|elt_b| (a.clone(), elt_b));
+                        self.a_cur = Some(a);
+                        self.b = b_back;
+                        elt
+                    }
+                }
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        // Not ExactSizeIterator because size may be larger than usize
+        let rest = size_hint::mul(self.a.size_hint(), self.b_orig.size_hint());
+        if self.a_cur.is_none() {
+            return rest
+        }
+        // Compute a * b_orig + b (+ b_back) for both lower and upper bound,
+        // where b is what remains of the row for the current element of a
+        let sh = size_hint::add(rest, self.b.size_hint());
+        match self.back {
+            None => sh,
+            Some((_, ref b_back)) => size_hint::add(sh, b_back.size_hint()),
+        }
+    }
+
+    fn fold<Acc, G>(mut self, mut accum: Acc, mut f: G) -> Acc where
+        G: FnMut(Acc, Self::Item) -> Acc,
+    {
+        // Run the inner iterator's own fold for each row, starting with
+        // what remains of the current row
+        let mut a_cur = match self.a_cur.take() {
+            None => return accum,
+            Some(x) => x,
+        };
+        let mut b = self.b;
+        loop {
+            accum = b.fold(accum, |acc, elt| f(acc, (a_cur.clone(), elt)));
+            a_cur = match self.a.next() {
+                None => break,
+                Some(x) => x,
+            };
+            b = self.b_orig.clone();
+        }
+        match self.back {
+            None => accum,
+            Some((a, b_back)) => b_back.fold(accum, |acc, elt| f(acc, (a.clone(), elt))),
+        }
+    }
+
+    fn count(self) -> usize
+    {
+        if self.a_cur.is_none() {
+            return 0
+        }
+        // The rest of the current row, then one full row per element of a,
+        // then the rest of the row started from the back
+        let rows = self.a.count();
+        let row_len = if rows > 0 { self.b_orig.count() } else { 0 };
+        let back_len = self.back.map_or(0, |(_, b_back)| b_back.count());
+        self.b.count() + rows * row_len + back_len
+    }
+
+    fn last(self) -> Option<Self::Item>
+    {
+        let a_cur = match self.a_cur {
+            None => return None,
+            Some(x) => x,
+        };
+        if let Some((a, b_back)) = self.back {
+            if let Some(elt_b) = b_back.last() {
+                return Some((a, elt_b));
+            }
+        }
+        match self.a.last() {
+            // The current row is the last one
+            None => self.b.last().map(|elt_b| (a_cur, elt_b)),
+            Some(a_last) => self.b_orig.last().map(|elt_b| (a_last, elt_b)),
+        }
+    }
+}
+
+impl<I, J> DoubleEndedIterator for Product<I, J> where
+    I: DoubleEndedIterator,
+    J: Clone + DoubleEndedIterator,
+    I::Item: Clone,
+{
+    fn next_back(&mut self) -> Option<(I::Item, J::Item)>
+    {
+        if let Some((ref a, ref mut b_back)) = self.back {
+            if let Some(elt_b) = b_back.next_back() {
+                return Some((a.clone(), elt_b));
+            }
+        }
+        let mut b = self.b_orig.clone();
+        let elt_b = match b.next_back() {
+            None => return None,
+            Some(x) => x,
+        };
+        match self.a.next_back() {
+            Some(a) => {
+                let elt = (a.clone(), elt_b);
+                self.back = Some((a, b));
+                Some(elt)
+            }
+            None => {
+                // The only row left is the front one
+                self.back = None;
+                match self.a_cur {
+                    None => None,
+                    Some(ref a) => self.b.next_back().map(|elt_b| (a.clone(), elt_b)),
+                }
+            }
+        }
+    }
+}
+
+/// An iterator adaptor that iterates over the pairs of the cartesian product
+/// of **I** and **J** that a predicate accepts.
+///
+/// See [*.filtered_cartesian_product()*](trait.Itertools.html#method.filtered_cartesian_product)
+/// for more information.
+#[derive(Clone)]
+pub struct FilteredProduct<I, J, F> where
+    I: Iterator,
+{
+    a: I,
+    a_cur: Option<I::Item>,
+    b: J,
+    b_orig: J,
+    keep: F,
+}
+
+impl<I, J, F> FilteredProduct<I, J, F> where
+    I: Iterator,
+    J: Clone + Iterator,
+{
+    /// Create a new **FilteredProduct** iterator.
+    pub fn new(i: I, j: J, keep: F) -> Self
+    {
+        let mut i = i;
+        FilteredProduct{a_cur: i.next(), a: i, b: j.clone(), b_orig: j, keep: keep}
+    }
+}
+
+impl<I, J, F> Iterator for FilteredProduct<I, J, F> where
+    I: Iterator,
+    J: Clone + Iterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, &J::Item) -> bool,
+{
+    type Item = (I::Item, J::Item);
+
+    fn next(&mut self) -> Option<(I::Item, J::Item)>
+    {
+        loop {
+            {
+                let a = match self.a_cur {
+                    None => return None,
+                    Some(ref a) => a,
+                };
+                // only accepted pairs are built, and clone the row element
+                while let Some(elt_b) = self.b.next() {
+                    if (self.keep)(a, &elt_b) {
+                        return Some((a.clone(), elt_b));
+                    }
+                }
+            }
+            self.a_cur = self.a.next();
+            self.b = self.b_orig.clone();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        if self.a_cur.is_none() {
+            return (0, Some(0))
+        }
+        let rest = size_hint::mul(self.a.size_hint(), self.b_orig.size_hint());
+        (0, size_hint::add(rest, self.b.size_hint()).1)
+    }
+}
+
+/// A “meta iterator adaptor”. Its closure recives a reference to the iterator
+/// and may pick off as many elements as it likes, to produce the next iterator element.
+///
+/// Iterator element type is *X*, if the return type of **F** is *Option\<X\>*.
+#[derive(Clone)]
+pub struct Batching<I, F> {
+    f: F,
+    iter: I,
+}
+
+impl<F, I> Batching<I, F> {
+    /// Create a new Batching iterator.
+    pub fn new(iter: I, f: F) -> Batching<I, F>
+    {
+        Batching{f: f, iter: iter}
+    }
+}
+
+impl<B, F, I> Iterator for Batching<I, F> where
+    I: Iterator,
+    F: FnMut(&mut I) -> Option<B>,
+{
+    type Item = B;
+    #[inline]
+    fn next(&mut self) -> Option<B>
+    {
+        (self.f)(&mut self.iter)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        // No information about closue behavior
+        (0, None)
+    }
+}
+
+/// A “meta iterator adaptor” like **Batching**, that also reports a size
+/// hint computed from the size hint of the base iterator.
+///
+/// See [*.batching_with_hint()*](trait.Itertools.html#method.batching_with_hint)
+/// for more information.
+///
+/// Iterator element type is *X*, if the return type of **F** is *Option\<X\>*.
+#[derive(Clone)]
+pub struct BatchingWithHint<I, F, H> {
+    f: F,
+    hint: H,
+    iter: I,
+}
+
+impl<F, H, I> BatchingWithHint<I, F, H> {
+    /// Create a new BatchingWithHint iterator.
+    pub fn new(iter: I, f: F, hint: H) -> BatchingWithHint<I, F, H>
+    {
+        BatchingWithHint{f: f, hint: hint, iter: iter}
+    }
+}
+
+impl<B, F, H, I> Iterator for BatchingWithHint<I, F, H> where
+    I: Iterator,
+    F: FnMut(&mut I) -> Option<B>,
+    H: Fn(size_hint::SizeHint) -> size_hint::SizeHint,
+{
+    type Item = B;
+    #[inline]
+    fn next(&mut self) -> Option<B>
+    {
+        (self.f)(&mut self.iter)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (self.hint)(self.iter.size_hint())
+    }
+}
+
+/// An iterator adaptor that yields each pair of adjacent elements.
+///
+/// See [*.pairwise()*](trait.Itertools.html#method.pairwise) for more information.
+#[derive(Clone)]
+pub struct Pairwise<I> where
+    I: Iterator,
+{
+    iter: Fuse<I>,
+    last: Option<I::Item>,
+}
+
+impl<I> Pairwise<I> where
+    I: Iterator,
+{
+    /// Create a new **Pairwise** iterator.
+    pub fn new(iter: I) -> Self
+    {
+        Pairwise{iter: iter.fuse(), last: None}
+    }
+}
+
+impl<I> Iterator for Pairwise<I> where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = (I::Item, I::Item);
+
+    fn next(&mut self) -> Option<(I::Item, I::Item)>
+    {
+        let prev = match self.last.take() {
+            Some(x) => x,
+            None => match self.iter.next() {
+                None => return None,
+                Some(x) => x,
+            },
+        };
+        self.iter.next().map(|curr| {
+            self.last = Some(curr.clone());
+            (prev, curr)
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let sh = size_hint::add_scalar(self.iter.size_hint(), self.last.is_some() as usize);
+        size_hint::sub_scalar(sh, 1)
+    }
+}
+
+impl<I> ExactSizeIterator for Pairwise<I> where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{ }
+
+/// An iterator adaptor that yields the running reduction of the base
+/// iterator, seeded by its first element.
+///
+/// See [*.scan1()*](trait.Itertools.html#method.scan1) for more information.
+#[derive(Clone)]
+pub struct Scan1<I, F> where
+    I: Iterator,
+{
+    iter: I,
+    acc: Option<I::Item>,
+    f: F,
+}
+
+impl<I, F> Scan1<I, F> where
+    I: Iterator,
+{
+    /// Create a new **Scan1** iterator.
+    pub fn new(iter: I, f: F) -> Self
+    {
+        Scan1{iter: iter, acc: None, f: f}
+    }
+}
+
+impl<I, F> Iterator for Scan1<I, F> where
+    I: Iterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, I::Item) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        let elt = match self.iter.next() {
+            None => return None,
+            Some(x) => x,
+        };
+        let acc = match self.acc.take() {
+            None => elt,
+            Some(acc) => (self.f)(&acc, elt),
+        };
+        self.acc = Some(acc.clone());
+        Some(acc)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
+}
+
+impl<I, F> ExactSizeIterator for Scan1<I, F> where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, I::Item) -> I::Item,
+{ }
+
+impl<I, F> FusedIterator for Scan1<I, F> where
+    I: FusedIterator,
+    I::Item: Clone,
+    F: FnMut(&I::Item, I::Item) -> I::Item,
+{ }
+
+/// Pull the next run from **iter** into **elts**, returning the previous
+/// run once an element with a different key shows up, or the last run once
+/// **iter** is exhausted.
+///
+/// **current_key** is the key of the run buffered in **elts**, if any. This
+/// is shared by **GroupBy** and **GroupByIndexed**.
+#[cfg(feature = "use_std")]
+fn next_group<K, J, G>(iter: J, current_key: &mut Option<K>, elts: &mut Vec<J::Item>,
+                       mut key: G) -> Option<(K, Vec<J::Item>)> where
+    K: PartialEq,
+    J: Iterator,
+    G: FnMut(&J::Item) -> K,
+{
+    for elt in iter {
+        let key = key(&elt);
+        match current_key.take() {
+            None => {}
+            Some(old_key) => if old_key != key {
+                *current_key = Some(key);
+                let v = mem::replace(elts, vec![elt]);
+                return Some((old_key, v))
+            },
+        }
+        *current_key = Some(key);
+        elts.push(elt);
+    }
+    match current_key.take() {
+        None => None,
+        Some(key) => {
+            let v = mem::replace(elts, Vec::new());
+            Some((key, v))
+        }
+    }
+}
+
+/// The size hint of the runs still to come, given that of the base
+/// iterator and whether a run is buffered.
+#[cfg(feature = "use_std")]
+fn group_size_hint(sh: size_hint::SizeHint, stored: bool) -> size_hint::SizeHint
+{
+    let mut sh = size_hint::add_scalar(sh, stored as usize);
+    if sh.0 > 0 {
+        sh.0 = 1;
+    }
+    sh
+}
+
+#[cfg(feature = "use_std")]
+#[derive(Clone)]
+/// An iterator adaptor that groups iterator elements. Consecutive elements
+/// that map to the same key (“runs”), are returned as the iterator elements.
+pub struct GroupBy<K, I, F> where
     I: Iterator,
 {
-    top: Option<I::Item>,
+    key: F,
     iter: I,
+    current_key: Option<K>,
+    elts: Vec<I::Item>,
 }
 
-impl<I> PutBack<I> where
+#[cfg(feature = "use_std")]
+impl<K, F, I> GroupBy<K, I, F> where
     I: Iterator,
 {
-    /// Iterator element type is `A`
-    #[inline]
-    pub fn new(it: I) -> Self
+    /// Create a new **GroupBy** iterator.
+    pub fn new(iter: I, key: F) -> Self
     {
-        PutBack{top: None, iter: it}
+        GroupBy{key: key, iter: iter, current_key: None, elts: Vec::new()}
     }
+}
 
-    /// Put back a single value to the front of the iterator.
-    ///
-    /// If a value is already in the put back slot, it is overwritten.
-    #[inline]
-    pub fn put_back(&mut self, x: I::Item)
+#[cfg(feature = "use_std")]
+impl<K, I, F> Iterator for GroupBy<K, I, F> where
+    K: PartialEq,
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (K, Vec<I::Item>);
+    fn next(&mut self) -> Option<(K, Vec<I::Item>)>
     {
-        self.top = Some(x)
+        next_group(self.iter.by_ref(), &mut self.current_key, &mut self.elts, &mut self.key)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        group_size_hint(self.iter.size_hint(), self.current_key.is_some())
     }
 }
 
-impl<I> Iterator for PutBack<I> where
+#[cfg(feature = "use_std")]
+impl<K, I, F> FusedIterator for GroupBy<K, I, F> where
+    K: PartialEq,
+    I: FusedIterator,
+    F: FnMut(&I::Item) -> K,
+{ }
+
+#[cfg(feature = "use_std")]
+#[derive(Clone)]
+/// An iterator adaptor that groups iterator elements like **GroupBy**, and
+/// also reports the index in the base iterator where each run starts.
+///
+/// See [*.group_by_indexed()*](trait.Itertools.html#method.group_by_indexed)
+/// for more information.
+pub struct GroupByIndexed<K, I, F> where
     I: Iterator,
 {
-    type Item = I::Item;
-    #[inline]
-    fn next(&mut self) -> Option<I::Item> {
-        match self.top {
-            None => self.iter.next(),
-            ref mut some => some.take(),
-        }
+    key: F,
+    iter: I,
+    current_key: Option<K>,
+    elts: Vec<I::Item>,
+    /// Number of elements pulled from **iter** so far.
+    index: usize,
+    /// Start index of the run buffered in **elts**.
+    start: usize,
+}
+
+#[cfg(feature = "use_std")]
+impl<K, F, I> GroupByIndexed<K, I, F> where
+    I: Iterator,
+{
+    /// Create a new **GroupByIndexed** iterator.
+    pub fn new(iter: I, key: F) -> Self
+    {
+        GroupByIndexed{key: key, iter: iter, current_key: None, elts: Vec::new(),
+                       index: 0, start: 0}
     }
-    #[inline]
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        // Not ExactSizeIterator because size may be larger than usize
-        size_hint::add_scalar(self.iter.size_hint(), self.top.is_some() as usize)
+}
+
+#[cfg(feature = "use_std")]
+impl<K, I, F> Iterator for GroupByIndexed<K, I, F> where
+    K: PartialEq,
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+{
+    type Item = (usize, K, Vec<I::Item>);
+    fn next(&mut self) -> Option<(usize, K, Vec<I::Item>)>
+    {
+        let (key, elts) = {
+            let index = &mut self.index;
+            let iter = self.iter.by_ref().inspect(|_| *index += 1);
+            match next_group(iter, &mut self.current_key, &mut self.elts, &mut self.key) {
+                None => return None,
+                Some(run) => run,
+            }
+        };
+        // a buffered run was started by the element pulled last
+        let next_start = if self.current_key.is_some() { self.index - 1 } else { self.index };
+        let start = mem::replace(&mut self.start, next_start);
+        Some((start, key, elts))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        group_size_hint(self.iter.size_hint(), self.current_key.is_some())
     }
 }
 
+#[cfg(feature = "use_std")]
+impl<K, I, F> FusedIterator for GroupByIndexed<K, I, F> where
+    K: PartialEq,
+    I: FusedIterator,
+    F: FnMut(&I::Item) -> K,
+{ }
+
+#[cfg(feature = "use_std")]
 #[derive(Clone)]
-/// An iterator adaptor that iterates over the cartesian product of
-/// the element sets of two iterators **I** and **J**.
+/// An iterator adaptor that splits the iterator elements into segments,
+/// starting a new segment between two neighbors for which the predicate
+/// returns **true**.
 ///
-/// Iterator element type is **(I::Item, J::Item)**.
-pub struct Product<I, J> where
+/// See [*.split_when()*](trait.Itertools.html#method.split_when) for more information.
+pub struct SplitWhen<I, F> where
     I: Iterator,
 {
-    a: I,
-    a_cur: Option<I::Item>,
-    b: J,
-    b_orig: J,
+    iter: Fuse<I>,
+    pending: Option<I::Item>,
+    f: F,
 }
 
-impl<I, J> Product<I, J> where
+#[cfg(feature = "use_std")]
+impl<I, F> SplitWhen<I, F> where
     I: Iterator,
-    J: Clone + Iterator,
-    I::Item: Clone,
 {
-    /// Create a new cartesian product iterator
-    ///
-    /// Iterator element type is **(I::Item, J::Item)**.
-    pub fn new(i: I, j: J) -> Self
+    /// Create a new **SplitWhen** iterator.
+    pub fn new(iter: I, f: F) -> Self
     {
-        let mut i = i;
-        Product{a_cur: i.next(), a: i, b: j.clone(), b_orig: j}
+        SplitWhen{iter: iter.fuse(), pending: None, f: f}
     }
 }
 
-
-impl<I, J> Iterator for Product<I, J> where
+#[cfg(feature = "use_std")]
+impl<I, F> Iterator for SplitWhen<I, F> where
     I: Iterator,
-    J: Clone + Iterator,
-    I::Item: Clone,
+    F: FnMut(&I::Item, &I::Item) -> bool,
 {
-    type Item = (I::Item, J::Item);
-    fn next(&mut self) -> Option<(I::Item, J::Item)>
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>>
     {
-        let elt_b = match self.b.next() {
-            None => {
-                self.b = self.b_orig.clone();
-                match self.b.next() {
-                    None => return None,
-                    Some(x) => {
-                        self.a_cur = self.a.next();
-                        x
-                    }
-                }
-            }
-            Some(x) => x
+        let first = match self.pending.take() {
+            None => match self.iter.next() {
+                None => return None,
+                Some(x) => x,
+            },
+            Some(x) => x,
         };
-        match self.a_cur {
-            None => None,
-            Some(ref a) => {
-                Some((a.clone(), elt_b))
+        let mut segment = vec![first];
+        for elt in &mut self.iter {
+            let split = (self.f)(&segment[segment.len() - 1], &elt);
+            if split {
+                self.pending = Some(elt);
+                return Some(segment)
             }
+            segment.push(elt);
         }
+        Some(segment)
     }
 
     fn size_hint(&self) -> (usize, Option<usize>)
     {
-        let has_cur = self.a_cur.is_some() as usize;
-        // Not ExactSizeIterator because size may be larger than usize
-        let (b, _) = self.b.size_hint();
-
-        // Compute a * b_orig + b for both lower and upper bound
-        size_hint::add_scalar(
-            size_hint::mul(self.a.size_hint(), self.b_orig.size_hint()),
-            b * has_cur)
+        let (_, hi) = size_hint::add_scalar(self.iter.size_hint(),
+                                            self.pending.is_some() as usize);
+        (0, hi)
     }
 }
 
-/// A “meta iterator adaptor”. Its closure recives a reference to the iterator
-/// and may pick off as many elements as it likes, to produce the next iterator element.
-///
-/// Iterator element type is *X*, if the return type of **F** is *Option\<X\>*.
+#[cfg(feature = "use_std")]
 #[derive(Clone)]
-pub struct Batching<I, F> {
+/// An iterator adaptor that splits the iterator elements into segments
+/// separated by the elements for which the predicate returns **true**.
+///
+/// See [*.split_on()*](trait.Itertools.html#method.split_on) for more information.
+pub struct SplitOn<I, F> {
+    iter: Fuse<I>,
     f: F,
-    iter: I,
+    trailing: bool,
 }
 
-impl<F, I> Batching<I, F> {
-    /// Create a new Batching iterator.
-    pub fn new(iter: I, f: F) -> Batching<I, F>
+#[cfg(feature = "use_std")]
+impl<I, F> SplitOn<I, F> where
+    I: Iterator,
+{
+    /// Create a new **SplitOn** iterator.
+    pub fn new(iter: I, f: F) -> Self
     {
-        Batching{f: f, iter: iter}
+        SplitOn{iter: iter.fuse(), f: f, trailing: false}
     }
 }
 
-impl<B, F, I> Iterator for Batching<I, F> where
+#[cfg(feature = "use_std")]
+impl<I, F> Iterator for SplitOn<I, F> where
     I: Iterator,
-    F: FnMut(&mut I) -> Option<B>,
+    F: FnMut(&I::Item) -> bool,
 {
-    type Item = B;
-    #[inline]
-    fn next(&mut self) -> Option<B>
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>>
     {
-        (self.f)(&mut self.iter)
+        let mut segment = Vec::new();
+        for elt in &mut self.iter {
+            if (self.f)(&elt) {
+                // a separator always owes the segment that follows it
+                self.trailing = true;
+                return Some(segment)
+            }
+            segment.push(elt);
+        }
+        if segment.is_empty() && !self.trailing {
+            None
+        } else {
+            self.trailing = false;
+            Some(segment)
+        }
     }
 
-    #[inline]
     fn size_hint(&self) -> (usize, Option<usize>)
     {
-        // No information about closue behavior
-        (0, None)
+        // every element may be a separator, and each ends a segment
+        let (_, hi) = size_hint::add_scalar(self.iter.size_hint(), 1);
+        (self.trailing as usize, hi)
     }
 }
 
+#[cfg(feature = "use_std")]
 #[derive(Clone)]
-/// An iterator adaptor that groups iterator elements. Consecutive elements
-/// that map to the same key (“runs”), are returned as the iterator elements.
-pub struct GroupBy<K, I, F> where
+/// An iterator adaptor that yields each window of **n** consecutive elements
+/// as a vector.
+///
+/// See [*.windows()*](trait.Itertools.html#method.windows) for more information.
+pub struct Windows<I> where
     I: Iterator,
 {
-    key: F,
-    iter: I,
-    current_key: Option<K>,
-    elts: Vec<I::Item>,
+    iter: Fuse<I>,
+    buf: VecDeque<I::Item>,
+    n: usize,
 }
 
-impl<K, F, I> GroupBy<K, I, F> where
+#[cfg(feature = "use_std")]
+impl<I> Windows<I> where
     I: Iterator,
 {
-    /// Create a new **GroupBy** iterator.
-    pub fn new(iter: I, key: F) -> Self
+    /// Create a new **Windows** iterator.
+    ///
+    /// **Panics** if **n** is zero.
+    pub fn new(iter: I, n: usize) -> Self
     {
-        GroupBy{key: key, iter: iter, current_key: None, elts: Vec::new()}
+        assert!(n != 0, "windows: window size must be non-zero");
+        Windows{iter: iter.fuse(), buf: VecDeque::with_capacity(n), n: n}
     }
 }
 
-impl<K, I, F> Iterator for GroupBy<K, I, F> where
-    K: PartialEq,
+#[cfg(feature = "use_std")]
+impl<I> Iterator for Windows<I> where
     I: Iterator,
-    F: FnMut(&I::Item) -> K,
+    I::Item: Clone,
 {
-    type Item = (K, Vec<I::Item>);
-    fn next(&mut self) -> Option<(K, Vec<I::Item>)>
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>>
     {
-        for elt in self.iter.by_ref() {
-            let key = (self.key)(&elt);
-            match self.current_key.take() {
-                None => {}
-                Some(old_key) => if old_key != key {
-                    self.current_key = Some(key);
-                    let v = mem::replace(&mut self.elts, vec![elt]);
-                    return Some((old_key, v))
-                },
-            }
-            self.current_key = Some(key);
-            self.elts.push(elt);
+        if self.buf.len() == self.n {
+            self.buf.pop_front();
         }
-        match self.current_key.take() {
-            None => None,
-            Some(key) => {
-                let v = mem::replace(&mut self.elts, Vec::new());
-                Some((key, v))
+        while self.buf.len() < self.n {
+            match self.iter.next() {
+                None => return None,
+                Some(elt) => self.buf.push_back(elt),
             }
         }
+        Some(self.buf.iter().cloned().collect())
     }
 
     fn size_hint(&self) -> (usize, Option<usize>)
     {
-        let stored_count = self.current_key.is_some() as usize;
-        let mut sh = size_hint::add_scalar(self.iter.size_hint(),
-                                           stored_count);
-        if sh.0 > 0 {
-            sh.0 = 1;
+        if self.buf.len() == self.n {
+            // one new window per remaining element
+            self.iter.size_hint()
+        } else {
+            let sh = size_hint::add_scalar(self.iter.size_hint(), self.buf.len());
+            size_hint::sub_scalar(sh, self.n - 1)
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> ExactSizeIterator for Windows<I> where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{ }
+
+#[cfg(feature = "use_std")]
+#[derive(Clone)]
+/// An iterator adaptor that yields the elements in chunks of **n**, as
+/// vectors. The last chunk may be shorter.
+///
+/// See [*.chunks()*](trait.Itertools.html#method.chunks) for more information.
+pub struct Chunks<I> {
+    iter: I,
+    n: usize,
+}
+
+#[cfg(feature = "use_std")]
+impl<I> Chunks<I> {
+    /// Create a new **Chunks** iterator.
+    ///
+    /// **Panics** if **n** is zero.
+    pub fn new(iter: I, n: usize) -> Self
+    {
+        assert!(n != 0, "chunks: chunk size must be non-zero");
+        Chunks{iter: iter, n: n}
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> Iterator for Chunks<I> where
+    I: Iterator,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>>
+    {
+        let chunk: Vec<_> = self.iter.by_ref().take(self.n).collect();
+        if chunk.is_empty() {
+            None
+        } else {
+            Some(chunk)
         }
-        sh
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        size_hint::div_scalar_ceil(self.iter.size_hint(), self.n)
     }
 }
 
+#[cfg(feature = "use_std")]
+impl<I> ExactSizeIterator for Chunks<I> where
+    I: ExactSizeIterator,
+{ }
+
+#[cfg(feature = "use_std")]
+impl<I> FusedIterator for Chunks<I> where
+    I: FusedIterator,
+{ }
+
 /// An iterator adaptor that steps a number elements in the base iterator
 /// for each iteration.
 ///
@@ -393,6 +1415,26 @@ impl<I> Iterator for Step<I> where I: Iterator
         };
         (div(low), high.map(div))
     }
+
+    fn nth(&mut self, n: usize) -> Option<I::Item>
+    {
+        match n.checked_mul(self.skip + 1) {
+            Some(m) => {
+                // advance the base iterator in one call
+                let elt = self.iter.nth(m);
+                self.iter.dropn(self.skip);
+                elt
+            }
+            None => {
+                for _ in 0..n {
+                    if self.next().is_none() {
+                        return None
+                    }
+                }
+                self.next()
+            }
+        }
+    }
 }
 
 // known size
@@ -400,6 +1442,22 @@ impl<I> ExactSizeIterator for Step<I> where
     I: ExactSizeIterator,
 { }
 
+impl<I> DoubleEndedIterator for Step<I> where
+    I: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<I::Item>
+    {
+        let len = self.iter.len();
+        if len == 0 {
+            return None
+        }
+        // trim the back so that the last element is one that .next() would yield
+        let trim = (len - 1) % (self.skip + 1);
+        self.iter.by_ref().rev().dropn(trim);
+        self.iter.next_back()
+    }
+}
+
 /// An iterator adaptor that merges the two base iterators in ascending order.
 /// If both base iterators are sorted (ascending), the result is sorted.
 ///
@@ -408,8 +1466,8 @@ pub struct Merge<I, J, F> where
     I: Iterator,
     J: Iterator<Item=I::Item>,
 {
-    a: Peekable<I>,
-    b: Peekable<J>,
+    a: PutBack<I>,
+    b: PutBack<J>,
     cmp: F,
     fused: Option<bool>,
 }
@@ -423,19 +1481,29 @@ impl<I, J, F> Merge<I, J, F> where
     pub fn new(a: I, b: J, cmp: F) -> Self
     {
         Merge {
-            a: a.peekable(),
-            b: b.peekable(),
+            a: PutBack::new(a),
+            b: PutBack::new(b),
             cmp: cmp,
             fused: None,
         }
     }
+
+    /// Stop merging and return the rest of both base iterators.
+    ///
+    /// An element that was looked at to decide the order, but not yet
+    /// yielded, is in the put back slot of its iterator, so no element is
+    /// lost.
+    pub fn into_parts(self) -> (PutBack<I>, PutBack<J>)
+    {
+        (self.a, self.b)
+    }
 }
 
 impl<I, J, F> Clone for Merge<I, J, F> where
     I: Iterator,
     J: Iterator<Item=I::Item>,
-    Peekable<I>: Clone,
-    Peekable<J>: Clone,
+    PutBack<I>: Clone,
+    PutBack<J>: Clone,
     F: Clone,
 {
     fn clone(&self) -> Self {
@@ -484,46 +1552,73 @@ impl<I, J, F> Iterator for Merge<I, J, F> where
         // Not ExactSizeIterator because size may be larger than usize
         size_hint::add(self.a.size_hint(), self.b.size_hint())
     }
+
+    fn fold<B, G>(mut self, init: B, mut f: G) -> B where
+        G: FnMut(B, I::Item) -> B,
+    {
+        let mut acc = init;
+        match self.fused {
+            Some(true) => return self.a.fold(acc, f),
+            Some(false) => return self.b.fold(acc, f),
+            None => {}
+        }
+        loop {
+            let less_than = match (self.a.peek(), self.b.peek()) {
+                (Some(a), Some(b)) => (self.cmp)(a, b) != Ordering::Greater,
+                _ => break,
+            };
+            let elt = if less_than { self.a.next() } else { self.b.next() };
+            if let Some(elt) = elt {
+                acc = f(acc, elt);
+            }
+        }
+        // at most one side is left
+        let acc = self.a.fold(acc, &mut f);
+        self.b.fold(acc, f)
+    }
 }
 
-#[cfg(feature = "unstable")]
+impl<I, J, F> FusedIterator for Merge<I, J, F> where
+    I: FusedIterator,
+    J: FusedIterator<Item=I::Item>,
+    F: FnMut(&I::Item, &I::Item) -> Ordering
+{ }
+
 /// An iterator adaptor that enumerates the iterator elements,
-/// with a custom starting value and integer type.
-pub struct EnumerateFrom<I, K>
-{
-    index: K,
+/// with indices starting from a custom value.
+///
+/// The index saturates at **usize::MAX**.
+///
+/// See [*.enumerate_from()*](trait.Itertools.html#method.enumerate_from) for more information.
+#[derive(Clone)]
+pub struct EnumerateFrom<I> {
+    index: usize,
     iter: I,
 }
 
-#[cfg(feature = "unstable")]
-impl<K, I> EnumerateFrom<I, K> where
+impl<I> EnumerateFrom<I> where
     I: Iterator,
 {
     /// Create a new **EnumerateFrom**.
-    pub fn new(iter: I, start: K) -> Self
+    pub fn new(iter: I, start: usize) -> Self
     {
         EnumerateFrom{index: start, iter: iter}
     }
 }
 
-#[cfg(feature = "unstable")]
-impl<K, I> Iterator for EnumerateFrom<I, K> where
-    K: Copy + One + Add<Output=K>,
+impl<I> Iterator for EnumerateFrom<I> where
     I: Iterator,
 {
-    type Item = (K, I::Item);
-    fn next(&mut self) -> Option<(K, I::Item)>
+    type Item = (usize, I::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<(usize, I::Item)>
     {
-        match self.iter.next() {
-            None => None,
-            Some(elt) => {
-                let index = self.index.clone();
-                // FIXME: Arithmetic needs to be wrapping here to be sane,
-                // imagine i8 counter to enumerate a sequence 0 to 127 inclusive.
-                self.index = self.index + K::one();
-                Some((index, elt))
-            }
-        }
+        self.iter.next().map(|elt| {
+            let index = self.index;
+            self.index = index.saturating_add(1);
+            (index, elt)
+        })
     }
 
     fn size_hint(&self) -> (usize, Option<usize>)
@@ -532,13 +1627,24 @@ impl<K, I> Iterator for EnumerateFrom<I, K> where
     }
 }
 
+impl<I> DoubleEndedIterator for EnumerateFrom<I> where
+    I: DoubleEndedIterator + ExactSizeIterator,
+{
+    fn next_back(&mut self) -> Option<(usize, I::Item)>
+    {
+        // the back index follows from the elements that remain before it
+        self.iter.next_back().map(|elt| {
+            (self.index.saturating_add(self.iter.len()), elt)
+        })
+    }
+}
+
 // Same size
-#[cfg(feature = "unstable")]
-impl<K, I> ExactSizeIterator for EnumerateFrom<I, K> where
-    K: Copy + One + Add<Output=K>,
+impl<I> ExactSizeIterator for EnumerateFrom<I> where
     I: ExactSizeIterator,
 { }
 
+#[cfg(feature = "use_std")]
 #[derive(Clone)]
 /// An iterator adaptor that allows the user to peek at multiple *.next()*
 /// values without advancing itself.
@@ -550,12 +1656,21 @@ pub struct MultiPeek<I> where
     index: usize,
 }
 
+#[cfg(feature = "use_std")]
 impl<I: Iterator> MultiPeek<I> {
     /// Create a **MultiPeek** iterator.
     pub fn new(iter: I) -> MultiPeek<I> {
         MultiPeek{ iter: iter.fuse(), buf: Vec::new(), index: 0 }
     }
 
+    /// Reset the peeking “cursor”, so that the next *.peek()* returns the
+    /// front element again.
+    ///
+    /// The buffered elements are kept; *.next()* also resets the cursor.
+    pub fn reset_peek(&mut self) {
+        self.index = 0;
+    }
+
     /// Works exactly like *.next()* with the only difference that it doesn't
     /// advance itself. *.peek()* can be called multiple times, to peek
     /// further ahead.
@@ -577,6 +1692,7 @@ impl<I: Iterator> MultiPeek<I> {
     }
 }
 
+#[cfg(feature = "use_std")]
 impl<I> Iterator for MultiPeek<I> where
     I: Iterator,
 {
@@ -597,7 +1713,32 @@ impl<I> Iterator for MultiPeek<I> where
     }
 }
 
+/// Only the front element is inspected; the peek cursor of an in-progress
+/// sequence of *.peek()* calls is left alone if the element is rejected.
+/// If the element is accepted, it is consumed like with *.next()*, so the
+/// peek cursor is reset.
+#[cfg(feature = "use_std")]
+impl<I> PeekingNext for MultiPeek<I> where
+    I: Iterator,
+{
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item> where
+        F: FnOnce(&Self::Item) -> bool,
+    {
+        if self.buf.is_empty() {
+            match self.iter.next() {
+                Some(x) => self.buf.push(x),
+                None => return None,
+            }
+        }
+        if !accept(&self.buf[0]) {
+            return None
+        }
+        self.next()
+    }
+}
+
 // Same size
+#[cfg(feature = "use_std")]
 impl<I> ExactSizeIterator for MultiPeek<I> where
     I: ExactSizeIterator,
 { }
@@ -662,9 +1803,305 @@ impl<I, F> Iterator for Coalesce<I, F> where
                                               self.last.is_some() as usize);
         ((low > 0) as usize, hi)
     }
+
+    fn fold<B, G>(self, init: B, mut g: G) -> B where
+        G: FnMut(B, I::Item) -> B,
+    {
+        let Coalesce { iter, last, mut f } = self;
+        let mut last = match last {
+            None => return init,
+            Some(x) => x,
+        };
+        // each run is merged by the base iterator's own try_fold, which
+        // breaks out at the first element that starts a new run
+        let mut iter = iter;
+        let mut acc = init;
+        loop {
+            match iter.try_fold(last, &mut f) {
+                Ok(last) => return g(acc, last),
+                Err((last_, next_)) => {
+                    acc = g(acc, last_);
+                    last = next_;
+                }
+            }
+        }
+    }
+}
+
+
+/// An iterator adaptor that removes duplicates from sections of consecutive
+/// identical elements.
+///
+/// It is double ended if the base iterator is. Iterating from the front
+/// yields the first element of each run, and from the back the last one;
+/// a run where both directions meet is yielded only once.
+///
+/// See [*.dedup()*](trait.Itertools.html#method.dedup) for more information.
+#[derive(Clone)]
+pub struct Dedup<I> where
+    I: Iterator,
+{
+    iter: Fuse<I>,
+    /// The element pulled from the front, not yet yielded.
+    last: Option<I::Item>,
+    /// The element pulled from the back, not yet yielded.
+    last_back: Option<I::Item>,
+}
+
+impl<I> Dedup<I> where
+    I: Iterator,
+{
+    /// Create a new **Dedup**.
+    pub fn new(iter: I) -> Self
+    {
+        Dedup{iter: iter.fuse(), last: None, last_back: None}
+    }
+}
+
+impl<I> Iterator for Dedup<I> where
+    I: Iterator,
+    I::Item: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        let last = match self.last.take().or_else(|| self.iter.next()) {
+            None => return self.last_back.take(),
+            Some(x) => x,
+        };
+        for next in &mut self.iter {
+            if next != last {
+                self.last = Some(next);
+                return Some(last)
+            }
+        }
+        // the rest of this run may be waiting at the back
+        if self.last_back.as_ref() == Some(&last) {
+            self.last_back = None;
+        }
+        Some(last)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let pending = self.last.is_some() as usize + self.last_back.is_some() as usize;
+        let (low, hi) = size_hint::add_scalar(self.iter.size_hint(), pending);
+        ((low > 0) as usize, hi)
+    }
+
+    fn fold<B, G>(self, init: B, mut g: G) -> B where
+        G: FnMut(B, I::Item) -> B,
+    {
+        let Dedup { iter, last, last_back } = self;
+        let mut iter = iter.chain(last_back);
+        let mut last = match last.or_else(|| iter.next()) {
+            None => return init,
+            Some(x) => x,
+        };
+        // as in Coalesce, the base iterator's try_fold skips each run
+        let mut acc = init;
+        loop {
+            let run = iter.try_fold(last, |last, next| {
+                if next == last { Ok(last) } else { Err((last, next)) }
+            });
+            match run {
+                Ok(last) => return g(acc, last),
+                Err((last_, next_)) => {
+                    acc = g(acc, last_);
+                    last = next_;
+                }
+            }
+        }
+    }
+}
+
+impl<I> DoubleEndedIterator for Dedup<I> where
+    I: DoubleEndedIterator,
+    I::Item: PartialEq,
+{
+    fn next_back(&mut self) -> Option<I::Item>
+    {
+        let last = match self.last_back.take().or_else(|| self.iter.next_back()) {
+            None => return self.last.take(),
+            Some(x) => x,
+        };
+        while let Some(next) = self.iter.next_back() {
+            if next != last {
+                self.last_back = Some(next);
+                return Some(last)
+            }
+        }
+        // the rest of this run may be waiting at the front
+        if self.last.as_ref() == Some(&last) {
+            self.last = None;
+        }
+        Some(last)
+    }
+}
+
+impl<I> FusedIterator for Dedup<I> where
+    I: Iterator,
+    I::Item: PartialEq,
+{ }
+
+
+/// An iterator adaptor that removes consecutive elements with equal keys.
+///
+/// The key of the last emitted element is cached, so the key function is
+/// called exactly once per element.
+///
+/// See [*.dedup_by_key()*](trait.Itertools.html#method.dedup_by_key) for more information.
+#[derive(Clone)]
+pub struct DedupByKey<I, F, K> where
+    I: Iterator,
+{
+    iter: I,
+    last: Option<(I::Item, K)>,
+    key: F,
+}
+
+impl<I, F, K> DedupByKey<I, F, K> where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+{
+    /// Create a new **DedupByKey**.
+    pub fn new(mut iter: I, mut key: F) -> Self
+    {
+        let last = iter.next().map(|elt| {
+            let k = key(&elt);
+            (elt, k)
+        });
+        DedupByKey {
+            iter: iter,
+            last: last,
+            key: key,
+        }
+    }
+}
+
+impl<I, F, K> Iterator for DedupByKey<I, F, K> where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    K: PartialEq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        // this fuses the iterator
+        let (last, last_key) = match self.last.take() {
+            None => return None,
+            Some(x) => x,
+        };
+        for next in &mut self.iter {
+            let next_key = (self.key)(&next);
+            if next_key != last_key {
+                self.last = Some((next, next_key));
+                return Some(last)
+            }
+        }
+
+        Some(last)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let (low, hi) = size_hint::add_scalar(self.iter.size_hint(),
+                                              self.last.is_some() as usize);
+        ((low > 0) as usize, hi)
+    }
+
+    fn fold<B, G>(self, init: B, mut g: G) -> B where
+        G: FnMut(B, I::Item) -> B,
+    {
+        let DedupByKey { iter, last, mut key } = self;
+        let mut last = match last {
+            None => return init,
+            Some(x) => x,
+        };
+        // as in Coalesce, the base iterator's try_fold skips each run
+        let mut iter = iter;
+        let mut acc = init;
+        loop {
+            let run = iter.try_fold(last, |last, next| {
+                let next_key = key(&next);
+                if next_key != last.1 { Err((last.0, (next, next_key))) } else { Ok(last) }
+            });
+            match run {
+                Ok(last) => return g(acc, last.0),
+                Err((last_, next_)) => {
+                    acc = g(acc, last_);
+                    last = next_;
+                }
+            }
+        }
+    }
+}
+
+
+/// An iterator adaptor that removes consecutive elements that a closure
+/// considers the same, counting the length of each run.
+///
+/// See [*.dedup_by_with_count()*](trait.Itertools.html#method.dedup_by_with_count)
+/// for more information.
+#[derive(Clone)]
+pub struct DedupByWithCount<I, F> where
+    I: Iterator,
+{
+    iter: I,
+    last: Option<(usize, I::Item)>,
+    same: F,
+}
+
+impl<I, F> DedupByWithCount<I, F> where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    /// Create a new **DedupByWithCount**.
+    pub fn new(mut iter: I, same: F) -> Self
+    {
+        let last = iter.next().map(|elt| (1, elt));
+        DedupByWithCount {
+            iter: iter,
+            last: last,
+            same: same,
+        }
+    }
 }
 
+impl<I, F> Iterator for DedupByWithCount<I, F> where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = (usize, I::Item);
+
+    fn next(&mut self) -> Option<(usize, I::Item)>
+    {
+        // this fuses the iterator
+        let (mut count, first) = match self.last.take() {
+            None => return None,
+            Some(x) => x,
+        };
+        for next in &mut self.iter {
+            if (self.same)(&first, &next) {
+                count += 1;
+            } else {
+                self.last = Some((1, next));
+                return Some((count, first))
+            }
+        }
+
+        Some((count, first))
+    }
 
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let (low, hi) = size_hint::add_scalar(self.iter.size_hint(),
+                                              self.last.is_some() as usize);
+        ((low > 0) as usize, hi)
+    }
+}
 
 /// An iterator adaptor that borrows from a **Clone**-able iterator
 /// to only pick off elements while the predicate returns **true**.
@@ -715,6 +2152,75 @@ impl<'a, I, F> Iterator for TakeWhileRef<'a, I, F> where
     }
 }
 
+/// An iterator adaptor that yields elements while the predicate returns
+/// **true**, and then also yields the first element for which it returns **false**.
+///
+/// This iterator is *fused*.
+#[derive(Clone)]
+pub struct TakeWhileInclusive<I, F> {
+    iter: I,
+    f: F,
+    done: bool,
+}
+
+impl<I, F> TakeWhileInclusive<I, F> where
+    I: Iterator,
+    F: FnMut(&I::Item) -> bool,
+{
+    /// Create a new **TakeWhileInclusive**.
+    pub fn new(iter: I, f: F) -> Self
+    {
+        TakeWhileInclusive {
+            iter: iter,
+            f: f,
+            done: false,
+        }
+    }
+}
+
+impl<I, F> Iterator for TakeWhileInclusive<I, F> where
+    I: Iterator,
+    F: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        if self.done {
+            return None
+        }
+        match self.iter.next() {
+            None => {
+                self.done = true;
+                None
+            }
+            Some(elt) => {
+                if !(self.f)(&elt) {
+                    self.done = true;
+                }
+                Some(elt)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        if self.done {
+            (0, Some(0))
+        } else {
+            // The terminating element comes from the base iterator too,
+            // so its upper bound still holds.
+            let (_, hi) = self.iter.size_hint();
+            (0, hi)
+        }
+    }
+}
+
+impl<I, F> FusedIterator for TakeWhileInclusive<I, F> where
+    I: Iterator,
+    F: FnMut(&I::Item) -> bool,
+{ }
+
 /// An iterator to iterate through all the combinations of pairs in a **Clone**-able iterator.
 #[derive(Clone)]
 pub struct Combinations<I: Iterator> {
@@ -761,9 +2267,16 @@ impl<I> Iterator for Combinations<I> where I: Iterator + Clone, I::Item: Clone{
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let (lo, hi) = self.iter.size_hint();
-        let (lo, hi) = size_hint::mul((lo, hi), (lo - 1, hi.map(|hi|hi - 1)));
+        // n * (n - 1) / 2 pairs among the elements after the current one
+        let sh = self.iter.size_hint();
+        let (lo, hi) = size_hint::mul(sh, size_hint::sub_scalar(sh, 1));
         // won't truncate because x * (x - 1) is guarenteed to be even
-        (lo / 2, hi.map(|hi| hi / 2))
+        let pairs = (lo / 2, hi.map(|hi| hi / 2));
+        if self.val.is_some() {
+            // and the pairs that remain for the current element
+            size_hint::add(pairs, self.next_iter.size_hint())
+        } else {
+            pairs
+        }
     }
 }
\ No newline at end of file
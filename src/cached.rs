@@ -0,0 +1,93 @@
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::vec::Vec;
+
+use size_hint;
+
+/// The state shared by all the handles of a **Cached** iterator.
+struct CachedBuffer<I> where
+    I: Iterator,
+{
+    iter: I,
+    done: bool,
+    buffer: Vec<I::Item>,
+}
+
+/// An iterator handle that memoizes the elements of a shared base iterator,
+/// so that each clone can iterate them independently from the beginning.
+///
+/// Elements are pulled from the base iterator on first demand by any
+/// handle, and later handles replay them from the shared cache.
+///
+/// See [*.cached()*](trait.Itertools.html#method.cached) for more information.
+///
+/// Iterator element type is **I::Item**.
+pub struct Cached<I> where
+    I: Iterator,
+{
+    shared: Rc<RefCell<CachedBuffer<I>>>,
+    index: usize,
+}
+
+impl<I> Cached<I> where
+    I: Iterator,
+{
+    /// Create a new **Cached** handle at the start of **iter**.
+    pub fn new(iter: I) -> Self
+    {
+        let buffer = CachedBuffer{iter: iter, done: false, buffer: Vec::new()};
+        Cached{shared: Rc::new(RefCell::new(buffer)), index: 0}
+    }
+
+    /// Return a new handle at the start of the shared sequence, which
+    /// replays the cached elements before pulling any new ones.
+    pub fn replay(&self) -> Self
+    {
+        Cached{shared: self.shared.clone(), index: 0}
+    }
+}
+
+/// A clone starts at the same position as the original handle.
+impl<I> Clone for Cached<I> where
+    I: Iterator,
+{
+    fn clone(&self) -> Self
+    {
+        Cached{shared: self.shared.clone(), index: self.index}
+    }
+}
+
+impl<I> Iterator for Cached<I> where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        let mut shared = self.shared.borrow_mut();
+        if self.index == shared.buffer.len() {
+            // past the high-water mark; the base iterator is only called
+            // again if it has not returned None yet
+            if shared.done {
+                return None;
+            }
+            match shared.iter.next() {
+                None => {
+                    shared.done = true;
+                    return None;
+                }
+                Some(elt) => shared.buffer.push(elt),
+            }
+        }
+        self.index += 1;
+        Some(shared.buffer[self.index - 1].clone())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let shared = self.shared.borrow();
+        let sh = if shared.done { (0, Some(0)) } else { shared.iter.size_hint() };
+        size_hint::add_scalar(sh, shared.buffer.len() - self.index)
+    }
+}
@@ -0,0 +1,132 @@
+use core::iter::Fuse;
+use std::vec::Vec;
+
+use size_hint;
+
+/// An iterator adaptor that iterates over the **n**-fold cartesian product
+/// of the elements of an iterator with itself.
+///
+/// The elements of the base iterator are buffered as they are pulled, so the
+/// base iterator is only iterated once.
+///
+/// See [*.cartesian_power()*](trait.Itertools.html#method.cartesian_power)
+/// for more information.
+///
+/// Iterator element type is **Vec\<I::Item\>**.
+#[derive(Clone)]
+pub struct CartesianPower<I> where
+    I: Iterator,
+{
+    iter: Fuse<I>,
+    pool: Vec<I::Item>,
+    /// Indices into **pool** of the most recently yielded vector; **None**
+    /// before the first one.
+    indices: Option<Vec<usize>>,
+    n: usize,
+    done: bool,
+}
+
+impl<I> CartesianPower<I> where
+    I: Iterator,
+{
+    /// Create a new **CartesianPower** from an iterator and the number of
+    /// factors **n**.
+    pub fn new(iter: I, n: usize) -> Self
+    {
+        CartesianPower {
+            iter: iter.fuse(),
+            pool: Vec::new(),
+            indices: None,
+            n: n,
+            done: false,
+        }
+    }
+}
+
+/// Number of vectors still to come from an alphabet of **len** elements,
+/// after the one at **indices**; **None** on overflow.
+fn remaining(len: usize, indices: &[usize]) -> Option<usize>
+{
+    // count the vectors after the current one digit by digit, like reading
+    // the "complement" of **indices** as a number in base **len**
+    let mut rem = 0usize;
+    for &i in indices {
+        rem = match rem.checked_mul(len).and_then(|r| r.checked_add(len - 1 - i)) {
+            Some(r) => r,
+            None => return None,
+        };
+    }
+    Some(rem)
+}
+
+impl<I> Iterator for CartesianPower<I> where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = Vec<I::Item>;
+
+    fn next(&mut self) -> Option<Vec<I::Item>>
+    {
+        if self.done {
+            return None;
+        }
+        if self.n == 0 {
+            self.done = true;
+            return Some(Vec::new());
+        }
+        match self.indices {
+            None => {
+                match self.iter.next() {
+                    None => {
+                        self.done = true;
+                        return None;
+                    }
+                    Some(elt) => self.pool.push(elt),
+                }
+                self.indices = Some(vec![0; self.n]);
+            }
+            Some(ref mut indices) => {
+                // advance the odometer, last position fastest
+                let mut pos = self.n;
+                loop {
+                    if pos == 0 {
+                        self.done = true;
+                        return None;
+                    }
+                    pos -= 1;
+                    indices[pos] += 1;
+                    if indices[pos] < self.pool.len() {
+                        break;
+                    }
+                    if let Some(elt) = self.iter.next() {
+                        self.pool.push(elt);
+                        break;
+                    }
+                    indices[pos] = 0;
+                }
+            }
+        }
+        let pool = &self.pool;
+        self.indices.as_ref().map(|indices| {
+            indices.iter().map(|&i| pool[i].clone()).collect()
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        if self.done {
+            return (0, Some(0));
+        }
+        let (lo, hi) = self.iter.size_hint();
+        let lo = lo.saturating_add(self.pool.len());
+        let hi = hi.and_then(|hi| hi.checked_add(self.pool.len()));
+        match self.indices {
+            None => size_hint::pow((lo, hi), self.n as u32),
+            Some(ref indices) => {
+                let low = remaining(lo, indices).unwrap_or(usize::max_value());
+                let high = hi.and_then(|hi| remaining(hi, indices));
+                (low, high)
+            }
+        }
+    }
+}
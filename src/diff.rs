@@ -0,0 +1,80 @@
+use adaptors::PutBack;
+
+/// A type returned by the [*diff_with()*](fn.diff_with.html) function.
+///
+/// **Diff** represents the way in which the elements yielded by the
+/// iterator **I** differ from those yielded by the iterator **J**.
+///
+/// Each variant carries the index at which the sequences diverge, and the
+/// rest of the iterators from that index on, starting with the element that
+/// differs.
+pub enum Diff<I, J> where
+    I: Iterator,
+    J: Iterator,
+{
+    /// The index of the first non-matching element along with both
+    /// iterators' remaining elements starting with the first mismatch.
+    FirstMismatch(usize, PutBack<I>, PutBack<J>),
+    /// The total number of elements that were in **J** along with the
+    /// remaining elements of **I**.
+    Shorter(usize, PutBack<I>),
+    /// The total number of elements that were in **I** along with the
+    /// remaining elements of **J**.
+    Longer(usize, PutBack<J>),
+}
+
+/// Compare every element yielded by both **i** and **j** with the given
+/// function in lock-step, and return a **Diff** describing the first
+/// difference, or **None** if the two sequences are equal.
+///
+/// The iterators are consumed up to and including the first difference,
+/// which is put back into the returned iterators.
+///
+/// ## Example
+///
+/// ```
+/// use itertools::diff_with;
+/// use itertools::Diff;
+///
+/// assert!(diff_with(0..3, vec![0, 1, 2], |a, b| a == b).is_none());
+///
+/// match diff_with(0..3, vec![0, 5, 2], |a, b| a == b) {
+///     Some(Diff::FirstMismatch(index, mut i, mut j)) => {
+///         assert_eq!(index, 1);
+///         assert_eq!((i.next(), j.next()), (Some(1), Some(5)));
+///     }
+///     _ => panic!("expected a mismatch"),
+/// }
+/// ```
+pub fn diff_with<I, J, F>(i: I, j: J, mut is_equal: F)
+    -> Option<Diff<I::IntoIter, J::IntoIter>> where
+    I: IntoIterator,
+    J: IntoIterator,
+    F: FnMut(&I::Item, &J::Item) -> bool,
+{
+    let mut i = i.into_iter();
+    let mut j = j.into_iter();
+    let mut idx = 0;
+    while let Some(i_elem) = i.next() {
+        match j.next() {
+            None => {
+                let mut rest = PutBack::new(i);
+                rest.put_back(i_elem);
+                return Some(Diff::Shorter(idx, rest));
+            }
+            Some(j_elem) => if !is_equal(&i_elem, &j_elem) {
+                let mut rest_i = PutBack::new(i);
+                let mut rest_j = PutBack::new(j);
+                rest_i.put_back(i_elem);
+                rest_j.put_back(j_elem);
+                return Some(Diff::FirstMismatch(idx, rest_i, rest_j));
+            },
+        }
+        idx += 1;
+    }
+    j.next().map(|j_elem| {
+        let mut rest = PutBack::new(j);
+        rest.put_back(j_elem);
+        Diff::Longer(idx, rest)
+    })
+}
@@ -0,0 +1,100 @@
+#[cfg(feature = "use_std")]
+use std::error::Error;
+use core::fmt::{self, Debug, Display, Formatter};
+
+use size_hint;
+
+/// Iterator returned for the error case of *.exactly_one()* and *.at_most_one()*.
+///
+/// The error iterator yields the elements that were already pulled from the
+/// source iterator, and then the rest of the source iterator, so that no
+/// elements are lost.
+///
+/// Iterator element type is **I::Item**.
+#[derive(Clone)]
+pub struct ExactlyOneError<I> where
+    I: Iterator,
+{
+    first_two: (Option<I::Item>, Option<I::Item>),
+    inner: I,
+    /// Whether this comes from *.at_most_one()*, for the error message.
+    at_most: bool,
+}
+
+impl<I> ExactlyOneError<I> where
+    I: Iterator,
+{
+    /// Create a new **ExactlyOneError** iterator.
+    pub(crate) fn new(first_two: (Option<I::Item>, Option<I::Item>), inner: I) -> Self
+    {
+        ExactlyOneError{first_two: first_two, inner: inner, at_most: false}
+    }
+
+    /// Create a new **ExactlyOneError** iterator for *.at_most_one()*.
+    pub(crate) fn new_at_most_one(first_two: (Option<I::Item>, Option<I::Item>), inner: I)
+        -> Self
+    {
+        ExactlyOneError{first_two: first_two, inner: inner, at_most: true}
+    }
+}
+
+impl<I> Iterator for ExactlyOneError<I> where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        if let Some(elt) = self.first_two.0.take() {
+            return Some(elt)
+        }
+        if let Some(elt) = self.first_two.1.take() {
+            return Some(elt)
+        }
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let stored = self.first_two.0.is_some() as usize +
+                     self.first_two.1.is_some() as usize;
+        size_hint::add_scalar(self.inner.size_hint(), stored)
+    }
+}
+
+impl<I> ExactSizeIterator for ExactlyOneError<I> where
+    I: ExactSizeIterator,
+{ }
+
+impl<I> Display for ExactlyOneError<I> where
+    I: Iterator,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        let expected = if self.at_most { "at most one" } else { "exactly one" };
+        match self.first_two {
+            (None, None) => write!(f, "got zero elements when {} was expected", expected),
+            _ => write!(f, "got at least 2 elements when {} was expected", expected),
+        }
+    }
+}
+
+impl<I> Debug for ExactlyOneError<I> where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result
+    {
+        f.debug_struct("ExactlyOneError")
+         .field("first_two", &self.first_two)
+         .field("inner", &self.inner)
+         .field("at_most", &self.at_most)
+         .finish()
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> Error for ExactlyOneError<I> where
+    I: Iterator + Debug,
+    I::Item: Debug,
+{ }
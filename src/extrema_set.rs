@@ -0,0 +1,74 @@
+use core::cmp::Ordering;
+use std::vec::Vec;
+
+/// Collect every element of **iterable** whose key compares as **best**
+/// against the keys of all others, in their input order.
+fn extrema_set_impl<I, K, F>(iterable: I, mut key: F, best: Ordering) -> Vec<I::Item> where
+    I: IntoIterator,
+    F: FnMut(&I::Item) -> K,
+    K: Ord,
+{
+    let mut iter = iterable.into_iter();
+    let mut result = Vec::new();
+    let mut best_key = match iter.next() {
+        None => return result,
+        Some(elt) => {
+            let k = key(&elt);
+            result.push(elt);
+            k
+        }
+    };
+    for elt in iter {
+        let k = key(&elt);
+        match k.cmp(&best_key) {
+            Ordering::Equal => result.push(elt),
+            ord if ord == best => {
+                result.clear();
+                result.push(elt);
+                best_key = k;
+            }
+            _ => {}
+        }
+    }
+    result
+}
+
+/// Return all elements of **iterable** that tie for the maximum key, in
+/// their input order.
+///
+/// The key function is called once per element. An empty input gives an
+/// empty **Vec**.
+///
+/// ```
+/// use itertools::max_set_by_key;
+///
+/// let scores = [("a", 3), ("b", 5), ("c", 5)];
+/// assert_eq!(max_set_by_key(&scores, |s| s.1), vec![&("b", 5), &("c", 5)]);
+/// ```
+pub fn max_set_by_key<I, K, F>(iterable: I, key: F) -> Vec<I::Item> where
+    I: IntoIterator,
+    F: FnMut(&I::Item) -> K,
+    K: Ord,
+{
+    extrema_set_impl(iterable, key, Ordering::Greater)
+}
+
+/// Return all elements of **iterable** that tie for the minimum key, in
+/// their input order.
+///
+/// The key function is called once per element. An empty input gives an
+/// empty **Vec**.
+///
+/// ```
+/// use itertools::min_set_by_key;
+///
+/// let words = ["bb", "a", "cc", "d"];
+/// assert_eq!(min_set_by_key(words.iter().cloned(), |w| w.len()), vec!["a", "d"]);
+/// ```
+pub fn min_set_by_key<I, K, F>(iterable: I, key: F) -> Vec<I::Item> where
+    I: IntoIterator,
+    F: FnMut(&I::Item) -> K,
+    K: Ord,
+{
+    extrema_set_impl(iterable, key, Ordering::Less)
+}
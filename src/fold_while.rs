@@ -0,0 +1,29 @@
+/// An enum used for controlling the execution of *.fold_while()*.
+///
+/// See [*.fold_while()*](trait.Itertools.html#method.fold_while) for more information.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FoldWhile<T> {
+    /// Continue folding with this value
+    Continue(T),
+    /// Fold is complete and will return this value
+    Done(T),
+}
+
+impl<T> FoldWhile<T> {
+    /// Return the inner value.
+    pub fn into_inner(self) -> T
+    {
+        match self {
+            FoldWhile::Continue(x) | FoldWhile::Done(x) => x,
+        }
+    }
+
+    /// Return **true** if **self** is **Done**, **false** if it is **Continue**.
+    pub fn is_done(&self) -> bool
+    {
+        match *self {
+            FoldWhile::Continue(_) => false,
+            FoldWhile::Done(_) => true,
+        }
+    }
+}
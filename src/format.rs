@@ -0,0 +1,114 @@
+use core::cell::Cell;
+use core::fmt;
+
+/// Format all iterator elements lazily, separated by **sep**.
+///
+/// The iterator is consumed when the **Format** value is displayed, so it
+/// can only be formatted once; formatting it again panics.
+///
+/// See [*.format()*](trait.Itertools.html#method.format) for more information.
+pub struct Format<'a, I> {
+    sep: &'a str,
+    inner: Cell<Option<I>>,
+}
+
+/// Format all iterator elements lazily, separated by **sep**, using a
+/// closure to format each element.
+///
+/// The iterator is consumed when the **FormatWith** value is displayed, so
+/// it can only be formatted once; formatting it again panics.
+///
+/// See [*.format_with()*](trait.Itertools.html#method.format_with) for more
+/// information.
+pub struct FormatWith<'a, I, F> {
+    sep: &'a str,
+    inner: Cell<Option<(I, F)>>,
+}
+
+/// Format all iterator elements lazily, separated by **sep**, using a
+/// closure that also receives the index of each element.
+///
+/// The iterator is consumed when the **FormatWithIndex** value is displayed,
+/// so it can only be formatted once; formatting it again panics.
+///
+/// See [*.format_with_index()*](trait.Itertools.html#method.format_with_index)
+/// for more information.
+pub struct FormatWithIndex<'a, I, F> {
+    sep: &'a str,
+    inner: Cell<Option<(I, F)>>,
+}
+
+pub fn new_format<'a, I>(iter: I, sep: &'a str) -> Format<'a, I> {
+    Format{sep: sep, inner: Cell::new(Some(iter))}
+}
+
+pub fn new_format_with<'a, I, F>(iter: I, sep: &'a str, f: F) -> FormatWith<'a, I, F> {
+    FormatWith{sep: sep, inner: Cell::new(Some((iter, f)))}
+}
+
+pub fn new_format_with_index<'a, I, F>(iter: I, sep: &'a str, f: F)
+    -> FormatWithIndex<'a, I, F>
+{
+    FormatWithIndex{sep: sep, inner: Cell::new(Some((iter, f)))}
+}
+
+/// Write each element of **iter** with **g**, writing **sep** between them.
+fn write_separated<I, G>(fmt: &mut fmt::Formatter, sep: &str, iter: I, mut g: G)
+    -> fmt::Result where
+    I: Iterator,
+    G: FnMut(usize, I::Item, &mut fmt::Formatter) -> fmt::Result,
+{
+    for (i, elt) in iter.enumerate() {
+        if i > 0 && !sep.is_empty() {
+            try!(fmt.write_str(sep));
+        }
+        try!(g(i, elt, fmt));
+    }
+    Ok(())
+}
+
+impl<'a, I> fmt::Display for Format<'a, I> where
+    I: Iterator,
+    I::Item: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        let iter = match self.inner.take() {
+            Some(iter) => iter,
+            None => panic!("Format: was already formatted once"),
+        };
+        write_separated(f, self.sep, iter, |_, elt, f| elt.fmt(f))
+    }
+}
+
+impl<'a, I, F> fmt::Display for FormatWith<'a, I, F> where
+    I: Iterator,
+    F: FnMut(I::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        let (iter, mut format) = match self.inner.take() {
+            Some(t) => t,
+            None => panic!("FormatWith: was already formatted once"),
+        };
+        write_separated(f, self.sep, iter, |_, elt, f| {
+            format(elt, &mut |disp: &dyn fmt::Display| disp.fmt(f))
+        })
+    }
+}
+
+impl<'a, I, F> fmt::Display for FormatWithIndex<'a, I, F> where
+    I: Iterator,
+    F: FnMut(usize, I::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        let (iter, mut format) = match self.inner.take() {
+            Some(t) => t,
+            None => panic!("FormatWithIndex: was already formatted once"),
+        };
+        write_separated(f, self.sep, iter, |i, elt, f| {
+            format(i, elt, &mut |disp: &dyn fmt::Display| disp.fmt(f))
+        })
+    }
+}
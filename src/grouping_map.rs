@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Add;
+
+/// **GroupingMap** is an intermediate struct for efficient group-and-fold
+/// operations. It groups the elements by their key and at the same time
+/// folds each group using some aggregating operation.
+///
+/// No method on this struct performs temporary allocations.
+///
+/// Created with *.into_grouping_map()*.
+#[derive(Clone)]
+#[must_use = "GroupingMap is lazy and do nothing unless consumed"]
+pub struct GroupingMap<I> {
+    iter: I,
+}
+
+impl<I> GroupingMap<I> {
+    /// Create a new **GroupingMap** from an iterator of key-value pairs.
+    pub fn new(iter: I) -> Self
+    {
+        GroupingMap{iter: iter}
+    }
+}
+
+impl<I, K, V> GroupingMap<I> where
+    I: Iterator<Item=(K, V)>,
+    K: Hash + Eq,
+{
+    /// Group the values by key, and fold each group using **f**, starting
+    /// from a clone of **init**.
+    ///
+    /// The closure receives the accumulator, the key of the group and the
+    /// next value.
+    ///
+    /// Return a **HashMap** associating the key of each group with the
+    /// result of folding that group.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![(1, 2), (2, 4), (1, 3), (2, 6)];
+    /// let lookup = data.into_iter()
+    ///                  .into_grouping_map()
+    ///                  .fold(0, |acc, &key, val| acc + key * val);
+    ///
+    /// assert_eq!(lookup[&1], 5);
+    /// assert_eq!(lookup[&2], 20);
+    /// assert_eq!(lookup.len(), 2);
+    /// ```
+    pub fn fold<R, F>(self, init: R, mut f: F) -> HashMap<K, R> where
+        R: Clone,
+        F: FnMut(R, &K, V) -> R,
+    {
+        let mut map = HashMap::new();
+        for (key, val) in self.iter {
+            // init is only cloned when a key is seen for the first time
+            let (key, acc) = match map.remove_entry(&key) {
+                Some((key, acc)) => (key, acc),
+                None => (key, init.clone()),
+            };
+            let acc = f(acc, &key, val);
+            map.insert(key, acc);
+        }
+        map
+    }
+
+    /// Group the values by key, and fold each group using **f**, starting
+    /// from the first value of the group.
+    fn fold_first<F>(self, mut f: F) -> HashMap<K, V> where
+        F: FnMut(V, V) -> V,
+    {
+        let mut map = HashMap::new();
+        for (key, val) in self.iter {
+            let (key, acc) = match map.remove_entry(&key) {
+                Some((key, acc)) => (key, f(acc, val)),
+                None => (key, val),
+            };
+            map.insert(key, acc);
+        }
+        map
+    }
+
+    /// Group the values by key and sum each group.
+    ///
+    /// Return a **HashMap** associating the key of each group with the
+    /// sum of that group.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(1, 2), (1, 3), (2, 10)].into_iter()
+    ///                  .into_grouping_map()
+    ///                  .sum();
+    ///
+    /// assert_eq!(lookup[&1], 5);
+    /// assert_eq!(lookup[&2], 10);
+    /// assert_eq!(lookup.len(), 2);
+    /// ```
+    pub fn sum(self) -> HashMap<K, V> where
+        V: Add<Output=V>,
+    {
+        self.fold_first(|acc, val| acc + val)
+    }
+
+    /// Group the values by key and find the maximum of each group.
+    ///
+    /// If several values are equally maximum, the last one is kept.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(1, 2), (1, 7), (2, 10), (1, 3)].into_iter()
+    ///                  .into_grouping_map()
+    ///                  .max();
+    ///
+    /// assert_eq!(lookup[&1], 7);
+    /// assert_eq!(lookup[&2], 10);
+    /// ```
+    pub fn max(self) -> HashMap<K, V> where
+        V: Ord,
+    {
+        self.fold_first(|acc, val| if val >= acc { val } else { acc })
+    }
+
+    /// Group the values by key and find the minimum of each group.
+    ///
+    /// If several values are equally minimum, the first one is kept.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(1, 2), (1, 7), (2, 10), (1, 3)].into_iter()
+    ///                  .into_grouping_map()
+    ///                  .min();
+    ///
+    /// assert_eq!(lookup[&1], 2);
+    /// assert_eq!(lookup[&2], 10);
+    /// ```
+    pub fn min(self) -> HashMap<K, V> where
+        V: Ord,
+    {
+        self.fold_first(|acc, val| if val < acc { val } else { acc })
+    }
+
+    /// Group the values by key and count the values in each group.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lookup = vec![(1, 'a'), (2, 'b'), (1, 'c')].into_iter()
+    ///                  .into_grouping_map()
+    ///                  .count();
+    ///
+    /// assert_eq!(lookup[&1], 2);
+    /// assert_eq!(lookup[&2], 1);
+    /// ```
+    pub fn count(self) -> HashMap<K, usize>
+    {
+        self.fold(0, |acc, _, _| acc + 1)
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::VecDeque;
+
+use size_hint;
+
+/// An iterator adaptor that alternates elements from an arbitrary number of
+/// base iterators, round-robin.
+///
+/// Each cycle yields one element from each base iterator that is not yet
+/// exhausted; exhausted iterators are skipped.
+///
+/// See [*interleave_all()*](fn.interleave_all.html) for more information.
+///
+/// Iterator element type is **I::Item**.
+#[derive(Clone)]
+pub struct InterleaveAll<I> {
+    iters: VecDeque<I>,
+}
+
+/// Create an iterator that alternates elements from all of **iterables**,
+/// round-robin, until all of them are exhausted.
+///
+/// The elements of each iterable keep their relative order.
+///
+/// Iterator element type is the common element type of the iterables.
+///
+/// ```
+/// use itertools::interleave_all;
+///
+/// let it = interleave_all(vec![vec![1, 2, 3], vec![], vec![10], vec![20, 21]]);
+/// assert!(itertools::equal(it, vec![1, 10, 20, 2, 21, 3]));
+/// ```
+pub fn interleave_all<J>(iterables: J) -> InterleaveAll<<J::Item as IntoIterator>::IntoIter> where
+    J: IntoIterator,
+    J::Item: IntoIterator,
+{
+    InterleaveAll {
+        iters: iterables.into_iter().map(|it| it.into_iter()).collect(),
+    }
+}
+
+impl<I> Iterator for InterleaveAll<I> where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        while let Some(mut iter) = self.iters.pop_front() {
+            if let Some(elt) = iter.next() {
+                // rotate the polled iterator to the back
+                self.iters.push_back(iter);
+                return Some(elt);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.iters.iter()
+                  .map(|i| i.size_hint())
+                  .fold((0, Some(0)), size_hint::add)
+    }
+}
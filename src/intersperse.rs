@@ -1,4 +1,4 @@
-use std::iter::Fuse;
+use core::iter::{Fuse, FusedIterator};
 use super::size_hint;
 
 #[derive(Clone)]
@@ -14,6 +14,8 @@ pub struct Intersperse<I> where
     element: I::Item,
     iter: Fuse<I>,
     peek: Option<I::Item>,
+    /// A separator is owed before the last element taken from the back.
+    back_sep: bool,
 }
 
 impl<I> Intersperse<I> where
@@ -23,7 +25,7 @@ impl<I> Intersperse<I> where
     pub fn new(iter: I, elt: I::Item) -> Self
     {
         let mut iter = iter.fuse();
-        Intersperse{peek: iter.next(), iter: iter, element: elt}
+        Intersperse{peek: iter.next(), iter: iter, element: elt, back_sep: false}
     }
 }
 
@@ -41,6 +43,9 @@ impl<I> Iterator for Intersperse<I> where
             self.peek = self.iter.next();
             if self.peek.is_some() {
                 Some(self.element.clone())
+            } else if self.back_sep {
+                self.back_sep = false;
+                Some(self.element.clone())
             } else {
                 None
             }
@@ -53,6 +58,34 @@ impl<I> Iterator for Intersperse<I> where
         let has_peek = self.peek.is_some() as usize;
         let sh = self.iter.size_hint();
         size_hint::add_scalar(
-            size_hint::add(sh, sh), has_peek)
+            size_hint::add(sh, sh), has_peek + self.back_sep as usize)
     }
 }
+
+impl<I> DoubleEndedIterator for Intersperse<I> where
+    I: DoubleEndedIterator,
+    I::Item: Clone,
+{
+    fn next_back(&mut self) -> Option<I::Item>
+    {
+        // Every element left in **iter** is preceded by a separator, so
+        // from the back it's element, separator, element, ... and finally
+        // the peeked first element.
+        if self.back_sep {
+            self.back_sep = false;
+            return Some(self.element.clone());
+        }
+        match self.iter.next_back() {
+            Some(elt) => {
+                self.back_sep = true;
+                Some(elt)
+            }
+            None => self.peek.take(),
+        }
+    }
+}
+
+impl<I> FusedIterator for Intersperse<I> where
+    I: Iterator,
+    I::Item: Clone,
+{ }
@@ -1,6 +1,8 @@
 use super::Itertools;
 use super::size_hint;
 use super::misc::GenericRange;
+use core::cmp;
+use core::ops::{Bound, RangeBounds};
 
 /// An iterator adaptor that yields a subset (a slice) of the base iterator.
 ///
@@ -27,10 +29,34 @@ impl<I> ISlice<I>
 {
     /// Create a new **ISlice**.
     pub fn new<R: GenericRange>(iter: I, range: R) -> Self
+    {
+        ISlice::with_bounds(iter,
+                            range.start().unwrap_or(0),
+                            range.end().unwrap_or(::core::usize::MAX))
+    }
+
+    /// Create a new **ISlice** from any **RangeBounds** value.
+    pub(crate) fn from_range_bounds<R: RangeBounds<usize>>(iter: I, range: R) -> Self
+    {
+        let start = match range.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&e) => e.saturating_add(1),
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => ::core::usize::MAX,
+        };
+        ISlice::with_bounds(iter, start, end)
+    }
+
+    /// A reversed range is empty: clamp **end** so it never precedes **start**.
+    fn with_bounds(iter: I, start: usize, end: usize) -> Self
     {
         ISlice {
-            start: range.start().unwrap_or(0),
-            end: range.end().unwrap_or(::std::usize::MAX),
+            start: start,
+            end: cmp::max(start, end),
             iter: iter,
         }
     }
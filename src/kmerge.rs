@@ -0,0 +1,174 @@
+use std::mem;
+use std::vec::Vec;
+
+use size_hint;
+
+/// Head element and Tail iterator pair
+///
+/// **PartialEq**, **Eq**, **PartialOrd** and **Ord** are not implemented;
+/// the heap is ordered by the **KMergeBy** predicate on the head elements.
+struct HeadTail<I> where
+    I: Iterator,
+{
+    head: I::Item,
+    tail: I,
+}
+
+impl<I> HeadTail<I> where
+    I: Iterator,
+{
+    /// Constructs a **HeadTail** from an **Iterator**. Returns **None** if
+    /// the **Iterator** is empty.
+    fn new(mut it: I) -> Option<HeadTail<I>>
+    {
+        let head = it.next();
+        head.map(|h| HeadTail{head: h, tail: it})
+    }
+
+    /// Get the next element and update **head**, returning the old head in
+    /// **Some**.
+    ///
+    /// Returns **None** when the tail is exhausted (only **head** then remains).
+    fn next(&mut self) -> Option<I::Item>
+    {
+        if let Some(next) = self.tail.next() {
+            Some(mem::replace(&mut self.head, next))
+        } else {
+            None
+        }
+    }
+
+    /// Hints at the size of the sequence, same as the **Iterator** method.
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        size_hint::add_scalar(self.tail.size_hint(), 1)
+    }
+}
+
+impl<I> Clone for HeadTail<I> where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self
+    {
+        HeadTail{head: self.head.clone(), tail: self.tail.clone()}
+    }
+}
+
+/// Make **data** a heap (min-heap w.r.t the sorting).
+fn heapify<T, S>(data: &mut [T], mut less_than: S) where
+    S: FnMut(&T, &T) -> bool,
+{
+    for i in (0..data.len() / 2).rev() {
+        sift_down(data, i, &mut less_than);
+    }
+}
+
+/// Sift down element at **index** (**heap** is a min-heap wrt the ordering)
+fn sift_down<T, S>(heap: &mut [T], index: usize, mut less_than: S) where
+    S: FnMut(&T, &T) -> bool,
+{
+    debug_assert!(index <= heap.len());
+    let mut pos = index;
+    let mut child = 2 * pos + 1;
+    // Require the right child to be present
+    // This allows to find the index of the smallest child without a branch
+    // that wouldn't be predicted if present
+    while child + 1 < heap.len() {
+        // pick the smaller of the two children
+        child += less_than(&heap[child + 1], &heap[child]) as usize;
+
+        // sift down is done if we are already in order
+        if !less_than(&heap[child], &heap[pos]) {
+            return;
+        }
+        heap.swap(pos, child);
+        pos = child;
+        child = 2 * pos + 1;
+    }
+    // Check if the last (left) child was an only child
+    // if it is then it has to be compared with the parent
+    if child + 1 == heap.len() && less_than(&heap[child], &heap[pos]) {
+        heap.swap(pos, child);
+    }
+}
+
+/// An iterator adaptor that merges an abitrary number of base iterators
+/// according to an ordering function.
+///
+/// The base iterators are kept in a binary heap ordered by their current
+/// head element, so each element is produced in *O(log k)* time for *k*
+/// base iterators. Exhausted iterators are dropped from the heap.
+///
+/// The relative order of equal elements from different base iterators
+/// is unspecified.
+///
+/// Iterator element type is **I::Item**.
+pub struct KMergeBy<I, F> where
+    I: Iterator,
+{
+    heap: Vec<HeadTail<I>>,
+    less_than: F,
+}
+
+/// An iterator adaptor that merges an abitrary number of base iterators
+/// in ascending order, created with *.kmerge()*.
+pub type KMerge<I> = KMergeBy<I, fn(&<I as Iterator>::Item, &<I as Iterator>::Item) -> bool>;
+
+impl<I, F> KMergeBy<I, F> where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    /// Create a new **KMergeBy** from an iterator of iterables.
+    pub fn new<J>(iterables: J, mut less_than: F) -> Self where
+        J: IntoIterator,
+        J::Item: IntoIterator<IntoIter=I, Item=I::Item>,
+    {
+        let iter = iterables.into_iter();
+        let (lower, _) = iter.size_hint();
+        let mut heap = Vec::with_capacity(lower);
+        heap.extend(iter.filter_map(|it| HeadTail::new(it.into_iter())));
+        heapify(&mut heap, |a, b| less_than(&a.head, &b.head));
+        KMergeBy{heap: heap, less_than: less_than}
+    }
+}
+
+impl<I, F> Clone for KMergeBy<I, F> where
+    I: Iterator + Clone,
+    I::Item: Clone,
+    F: Clone,
+{
+    fn clone(&self) -> Self
+    {
+        KMergeBy{heap: self.heap.clone(), less_than: self.less_than.clone()}
+    }
+}
+
+impl<I, F> Iterator for KMergeBy<I, F> where
+    I: Iterator,
+    F: FnMut(&I::Item, &I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        if self.heap.is_empty() {
+            return None;
+        }
+        let result = if let Some(next) = self.heap[0].next() {
+            next
+        } else {
+            self.heap.swap_remove(0).head
+        };
+        let less_than = &mut self.less_than;
+        sift_down(&mut self.heap, 0, |a, b| less_than(&a.head, &b.head));
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.heap.iter()
+                 .map(|i| i.size_hint())
+                 .fold((0, Some(0)), size_hint::add)
+    }
+}
@@ -1,6 +1,7 @@
 #![warn(missing_docs)]
-#![cfg_attr(feature = "unstable", feature(core, zero_one))]
+#![cfg_attr(feature = "unstable", feature(core))]
 #![crate_name="itertools"]
+#![no_std]
 
 //! Itertools — extra iterator adaptors, functions and macros.
 //!
@@ -21,6 +22,14 @@
 //! extern crate itertools;
 //! ```
 //!
+//! ## Crate Features
+//!
+//! - `use_std`
+//!   - Enabled by default.
+//!   - Disable to compile itertools using `#![no_std]`. This disables
+//!     any items that depend on collections (like **group_by**, **tee**,
+//!     **kmerge**, **join** and many more).
+//!
 //! ## License
 //! Dual-licensed to be compatible with the Rust project.
 //!
@@ -32,52 +41,154 @@
 //!
 //!
 
-use std::iter::{self, IntoIterator};
+#[cfg(feature = "use_std")]
+#[macro_use]
+extern crate std;
+
+use core::iter::{self, IntoIterator};
+use core::cmp::Ordering;
+use core::ops::RangeBounds;
+use core::fmt;
+#[cfg(feature = "use_std")]
+use std::borrow::ToOwned;
+#[cfg(feature = "use_std")]
 use std::fmt::Write;
-use std::cmp::Ordering;
+#[cfg(feature = "use_std")]
+use std::collections::HashSet;
+#[cfg(feature = "use_std")]
+use std::hash::Hash;
+#[cfg(feature = "use_std")]
+use std::io;
+#[cfg(feature = "use_std")]
+use std::string::String;
+#[cfg(feature = "use_std")]
+use std::vec::{self, Vec};
+
+#[doc(hidden)]
+pub use core::iter as __std_iter;
 
 pub use adaptors::{
     Interleave,
     Product,
+    FilteredProduct,
     PutBack,
     FnMap,
+    MapInto,
+    Positions,
+    PositionRuns,
+    FlatMapOk,
+    FilterMapOk,
     Batching,
-    GroupBy,
+    BatchingWithHint,
+    Pairwise,
+    Scan1,
     Step,
     Merge,
-    MultiPeek,
     TakeWhileRef,
+    TakeWhileInclusive,
     Coalesce,
     CoalesceFn,
+    Dedup,
+    DedupByKey,
+    DedupByWithCount,
+    EnumerateFrom,
     Combinations,
 };
-#[cfg(feature = "unstable")]
-pub use adaptors::EnumerateFrom;
+#[cfg(feature = "use_std")]
+pub use adaptors::{Chunks, GroupBy, GroupByIndexed, MultiPeek, SplitOn, SplitWhen, Windows};
+#[cfg(feature = "use_std")]
+pub use cached::Cached;
+#[cfg(feature = "use_std")]
+pub use cartesian_power::CartesianPower;
+pub use diff::{diff_with, Diff};
+pub use exactly_one_err::ExactlyOneError;
+#[cfg(feature = "use_std")]
+pub use extrema_set::{max_set_by_key, min_set_by_key};
+pub use fold_while::FoldWhile;
+pub use format::{Format, FormatWith, FormatWithIndex};
+#[cfg(feature = "use_std")]
+pub use grouping_map::GroupingMap;
+#[cfg(feature = "use_std")]
+pub use interleave_all::{interleave_all, InterleaveAll};
 pub use intersperse::Intersperse;
 pub use islice::{ISlice};
-pub use repeatn::RepeatN;
-pub use rciter::RcIter;
+#[cfg(feature = "use_std")]
+pub use kmerge::{KMerge, KMergeBy};
+pub use minmax::MinMaxResult;
+pub use pad_tail::PadUsing;
+pub use peek_back::{peekable_back, PeekBack};
+pub use peeking_take_while::{PeekingNext, PeekingTakeWhile};
+pub use process_results_impl::{process_results, ProcessResults};
+#[cfg(feature = "use_std")]
+pub use put_back_n_impl::{put_back_n, PutBackN};
+pub use repeatn::{repeat_n, RepeatN};
+#[cfg(feature = "use_std")]
+pub use rciter::{rciter, RcIter};
+#[cfg(feature = "use_std")]
+pub use shared_iter::{sharediter, SharedIter};
+#[cfg(feature = "use_std")]
+pub use sorted_iter::SortedIter;
+pub use sources::{iterate, Iterate, repeat_call, RepeatCall, tabulate, Tabulate, unfold, Unfold};
 pub use stride::Stride;
 pub use stride::StrideMut;
-pub use tee::Tee;
+#[cfg(feature = "use_std")]
+pub use tee::{Tee, TeeHandle};
+#[cfg(feature = "use_std")]
+pub use tuple_combinations::TupleCombinations;
 pub use times::Times;
 pub use times::times;
+#[cfg(feature = "use_std")]
+pub use unique_impl::UniqueByOwned;
 pub use linspace::{linspace, Linspace};
-pub use zip_longest::{ZipLongest, EitherOrBoth};
+pub use zip_longest::{ZipLongest, ZipPad, EitherOrBoth};
 pub use ziptuple::{Zip};
 #[cfg(feature = "unstable")]
 pub use ziptrusted::{ZipTrusted, TrustedIterator};
 mod adaptors;
+#[cfg(feature = "use_std")]
+mod cached;
+#[cfg(feature = "use_std")]
+mod cartesian_power;
+mod diff;
+mod exactly_one_err;
+#[cfg(feature = "use_std")]
+mod extrema_set;
+mod fold_while;
+mod format;
+#[cfg(feature = "use_std")]
+mod grouping_map;
+#[cfg(feature = "use_std")]
+mod interleave_all;
 mod intersperse;
 mod islice;
+#[cfg(feature = "use_std")]
+mod kmerge;
 mod linspace;
+mod minmax;
 pub mod misc;
+mod pad_tail;
+mod peek_back;
+mod peeking_take_while;
+mod process_results_impl;
+#[cfg(feature = "use_std")]
+mod put_back_n_impl;
+#[cfg(feature = "use_std")]
 mod rciter;
 mod repeatn;
 pub mod size_hint;
+#[cfg(feature = "use_std")]
+mod shared_iter;
+#[cfg(feature = "use_std")]
+mod sorted_iter;
+mod sources;
 mod stride;
+#[cfg(feature = "use_std")]
 mod tee;
 mod times;
+#[cfg(feature = "use_std")]
+mod tuple_combinations;
+#[cfg(feature = "use_std")]
+mod unique_impl;
 mod zip_longest;
 mod ziptuple;
 #[cfg(feature = "unstable")]
@@ -89,6 +200,21 @@ pub type MapFn<I, B> where I: Iterator = iter::Map<I, fn(I::Item) -> B>;
 /// An ascending order merge iterator created with *.merge()*.
 pub type MergeAscend<I, J> where I: Iterator = Merge<I, J, fn(&I::Item, &I::Item) -> Ordering>;
 
+/// The iterator created with *.flatten_result_options()*, which drops
+/// **Ok(None)** elements.
+pub type FlattenResultOptions<I, T> = FilterMapOk<I, fn(Option<T>) -> Option<T>>;
+
+/// The tuple flattening iterator created with *.cons_tuples()*.
+pub type ConsTuples<I> = misc::FlatTuples<I>;
+
+/// The error returned by *.all_equal_value()*: **None** for an empty
+/// iterator, or the first element and the first element that differs from it.
+pub type AllEqualValueError<Item> = Option<(Item, Item)>;
+
+/// The eager run grouping iterator created with *.chunk_by_key()*.
+#[cfg(feature = "use_std")]
+pub type ChunkByKey<I, K, F> = GroupBy<K, I, F>;
+
 #[macro_export]
 /// Create an iterator over the “cartesian product” of iterators.
 ///
@@ -110,7 +236,7 @@ pub type MergeAscend<I, J> where I: Iterator = Merge<I, J, fn(&I::Item, &I::Item
 /// ```
 macro_rules! iproduct {
     ($I:expr) => (
-        (::std::iter::IntoIterator::into_iter($I))
+        ($crate::__std_iter::IntoIterator::into_iter($I))
     );
     ($I:expr, $J:expr) => (
         $crate::Product::new(iproduct!($I), iproduct!($J))
@@ -136,6 +262,13 @@ macro_rules! iproduct {
 /// from iterators `(I, J, ..., M)` implementing `I: Iterator<A>`,
 /// `J: Iterator<B>`, ..., `M: Iterator<E>`
 ///
+/// Each argument is converted with **IntoIterator**, and any number of
+/// iterators can be zipped: the macro expands to a chain of *.zip()* calls
+/// followed by a *.map()* that flattens the nested pairs into one flat
+/// tuple. With one argument, the result is just that argument's iterator
+/// (not a 1-tuple). Calling **izip!()** without arguments is a compile
+/// error. A trailing comma is allowed.
+///
 /// ## Example
 ///
 /// ```
@@ -151,12 +284,62 @@ macro_rules! iproduct {
 /// # }
 /// ```
 macro_rules! izip {
-    ($I:expr) => (
-        (::std::iter::IntoIterator::into_iter($I))
+    // @closure builds the closure that flattens the nested pairs, eg.
+    // izip!(@closure ((a, b), c) => (a, b, c), dd, ee)
+    // adds one pattern variable per remaining iterator expression
+    (@closure $p:pat => $tup:expr) => (
+        |$p| $tup
+    );
+    (@closure $p:pat => ($($tup:tt)*), $_iter:expr $(, $tail:expr)*) => (
+        izip!(@closure ($p, b) => ($($tup)*, b) $(, $tail)*)
+    );
+    () => (
+        compile_error!("izip! needs at least one iterator")
+    );
+    ($first:expr $(,)*) => (
+        $crate::__std_iter::IntoIterator::into_iter($first)
+    );
+    ($first:expr, $second:expr $(,)*) => (
+        izip!($first).zip($second)
+    );
+    ($first:expr $(, $rest:expr)* $(,)*) => (
+        izip!($first)
+            $(.zip($rest))*
+            .map(izip!(@closure a => (a) $(, $rest)*))
     );
-    ($($I:expr),*) => (
+}
+
+#[macro_export]
+/// Create an iterator that chains multiple iterators end to end.
+///
+/// Each argument is converted with **IntoIterator**, and all of them must
+/// have the same element type. With no arguments, the iterator is empty;
+/// with one argument, it is just that argument's iterator.
+///
+/// Iterator element type is the common element type of the arguments.
+///
+/// ## Example
+///
+/// ```
+/// #[macro_use]
+/// extern crate itertools;
+/// # fn main() {
+/// let all = chain!(0..2, vec![2, 3], Some(4));
+/// assert!(itertools::equal(all, vec![0, 1, 2, 3, 4]));
+/// # }
+/// ```
+macro_rules! chain {
+    () => (
+        $crate::__std_iter::empty()
+    );
+    ($first:expr $(, $rest:expr)* $(,)*) => (
         {
-            $crate::Zip::new(($(izip!($I)),*))
+            let iter = $crate::__std_iter::IntoIterator::into_iter($first);
+            $(
+                let iter = $crate::__std_iter::Iterator::chain(
+                    iter, $crate::__std_iter::IntoIterator::into_iter($rest));
+            )*
+            iter
         }
     );
 }
@@ -236,12 +419,43 @@ pub trait Itertools : Iterator {
         Interleave::new(self, other.into_iter())
     }
 
+    /// Alternate elements from all of the iterables produced by this
+    /// iterator, round-robin, until all of them are exhausted.
+    ///
+    /// Each cycle yields one element from each iterable that is not yet
+    /// exhausted. The elements of each iterable keep their relative order.
+    ///
+    /// Iterator element type is **Self::Item::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = vec![0..3, 10..11, 20..22].into_iter().interleave_all();
+    /// assert!(itertools::equal(it, vec![0, 10, 20, 1, 21, 2]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn interleave_all(self) -> InterleaveAll<<Self::Item as IntoIterator>::IntoIter> where
+        Self: Sized,
+        Self::Item: IntoIterator,
+    {
+        interleave_all(self)
+    }
+
     /// An iterator adaptor to insert a particular value
     /// between each element of the adapted iterator.
     ///
     /// Iterator element type is **Self::Item**.
     ///
-    /// This iterator is *fused*.
+    /// This iterator is *fused*, and double ended if **self** is.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (1..4).intersperse(0).rev();
+    /// assert!(itertools::equal(it, vec![3, 0, 2, 0, 1]));
+    /// ```
     fn intersperse(self, element: Self::Item) -> Intersperse<Self> where
         Self: Sized,
         Self::Item: Clone
@@ -249,6 +463,31 @@ pub trait Itertools : Iterator {
         Intersperse::new(self, element)
     }
 
+    /// Pad the iterator to a minimum length of **min** elements, filling in
+    /// each missing element with **f**, which is passed the position of the
+    /// element.
+    ///
+    /// The adaptor is double ended and has an exact size if the iterator
+    /// does; from the back, the padding comes first.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..3).pad_using(5, |i| 10 * i);
+    /// assert!(itertools::equal(it.clone(), vec![0, 1, 2, 30, 40]));
+    /// assert!(itertools::equal(it.rev(), vec![40, 30, 2, 1, 0]));
+    /// ```
+    fn pad_using<F>(self, min: usize, f: F) -> PadUsing<Self, F> where
+        Self: Sized,
+        F: FnMut(usize) -> Self::Item,
+    {
+        PadUsing::new(self, min, f)
+    }
+
     /// Create an iterator which iterates over both this and the specified
     /// iterator simultaneously, yielding pairs of two optional elements.
     ///
@@ -275,6 +514,38 @@ pub trait Itertools : Iterator {
         ZipLongest::new(self, other.into_iter())
     }
 
+    /// Create an iterator which iterates over both this and the specified
+    /// iterator simultaneously, yielding pairs of elements. When one of the
+    /// iterators is exhausted, its missing elements are produced by calling
+    /// its fill closure: **fill_left** for this iterator and **fill_right**
+    /// for the other one.
+    ///
+    /// The fill closures are only called for the missing elements, so the
+    /// fill values need not be **Clone**.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// Iterator element type is **(Self::Item, J::Item)**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..2).zip_pad("abcd".chars(), || -1, || '_');
+    /// assert!(itertools::equal(it, vec![(0, 'a'), (1, 'b'), (-1, 'c'), (-1, 'd')]));
+    /// ```
+    #[inline]
+    fn zip_pad<J, FA, FB>(self, other: J, fill_left: FA, fill_right: FB)
+        -> ZipPad<Self, J::IntoIter, FA, FB> where
+        J: IntoIterator,
+        FA: FnMut() -> Self::Item,
+        FB: FnMut() -> J::Item,
+        Self: Sized,
+    {
+        ZipPad::new(self, other.into_iter(), fill_left, fill_right)
+    }
+
     /// A “meta iterator adaptor”. Its closure recives a reference to the iterator
     /// and may pick off as many elements as it likes, to produce the next iterator element.
     ///
@@ -306,257 +577,829 @@ pub trait Itertools : Iterator {
         Batching::new(self, f)
     }
 
+    /// Like *.batching()*, but with a function **hint** that maps the size
+    /// hint of the iterator to the size hint of the adaptor, which otherwise
+    /// has to report **(0, None)**.
+    ///
+    /// The hint must be conservative: for any remaining base iterator
+    /// within its own size hint, the number of elements the closure still
+    /// produces must be within the bounds **hint** returns for it. The
+    /// helpers in [**size_hint**](size_hint/index.html) are useful here.
+    ///
+    /// Iterator element type is **B**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // Gather elements up in pairs, dropping an unpaired last element
+    /// let pit = (0..5).batching_with_hint(
+    ///     |it| it.next().and_then(|x| it.next().map(|y| (x, y))),
+    ///     |(lo, hi)| (lo / 2, hi.map(|hi| hi / 2)));
+    ///
+    /// assert_eq!(pit.size_hint(), (2, Some(2)));
+    /// assert!(itertools::equal(pit, vec![(0, 1), (2, 3)]));
+    /// ```
+    fn batching_with_hint<B, F, H>(self, f: F, hint: H) -> BatchingWithHint<Self, F, H> where
+        F: FnMut(&mut Self) -> Option<B>,
+        H: Fn(size_hint::SizeHint) -> size_hint::SizeHint,
+        Self: Sized,
+    {
+        BatchingWithHint::new(self, f, hint)
+    }
+
     /// Group iterator elements. Consecutive elements that map to the same key (“runs”),
     /// are returned as the iterator elements of **GroupBy**.
     ///
+    /// Only one run is buffered at a time. Each run is returned as an owned
+    /// vector that does not borrow from the **GroupBy**, so groups can be
+    /// stored or sent elsewhere after the adaptor is gone, and used in any
+    /// order. Being vectors, they know their exact length and iterate in
+    /// both directions.
+    ///
+    /// **GroupBy** is fused if the base iterator is.
+    ///
     /// Iterator element type is **(K, Vec\<Self::Item\>)**
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let groups = vec![1, 3, -2, -2, 1, 0].into_iter()
+    ///                  .group_by(|&x| x > 0)
+    ///                  .collect_vec();
+    /// assert_eq!(groups, vec![(true, vec![1, 3]), (false, vec![-2, -2]),
+    ///                         (true, vec![1]), (false, vec![0])]);
+    /// ```
+    #[cfg(feature = "use_std")]
     fn group_by<K, F: FnMut(&Self::Item) -> K>(self, key: F) -> GroupBy<K, Self, F> where
         Self: Sized,
     {
         GroupBy::new(self, key)
     }
 
-    /// Split into an iterator pair that both yield all elements from
-    /// the original iterator.
+    /// Group iterator elements like *.group_by()*, and also report the
+    /// index of the first element of each run in the base iterator.
     ///
-    /// **Note:** If the iterator is clonable, prefer using that instead
-    /// of using this method. It is likely to be more efficient.
+    /// Elements are counted as they are pulled from the base iterator, so
+    /// the start index of a run is its absolute position in the original
+    /// sequence, which is useful to map groups back to their source.
     ///
-    /// Iterator element type is **Self::Item**.
+    /// Iterator element type is **(usize, K, Vec\<Self::Item\>)**
     ///
     /// ## Example
+    ///
     /// ```
     /// use itertools::Itertools;
-    /// let xs = vec![0, 1, 2, 3];
     ///
-    /// let (mut t1, mut t2) = xs.into_iter().tee();
-    /// assert_eq!(t1.next(), Some(0));
-    /// assert_eq!(t1.next(), Some(1));
-    /// assert_eq!(t2.next(), Some(0));
-    /// assert_eq!(t1.next(), Some(2));
-    /// assert_eq!(t1.next(), Some(3));
-    /// assert_eq!(t1.next(), None);
-    /// assert_eq!(t2.next(), Some(1));
+    /// let starts = "aabbbc".chars()
+    ///                      .group_by_indexed(|&c| c)
+    ///                      .map(|(start, key, _)| (start, key))
+    ///                      .collect_vec();
+    /// assert_eq!(starts, vec![(0, 'a'), (2, 'b'), (5, 'c')]);
     /// ```
-    fn tee(self) -> (Tee<Self>, Tee<Self>) where
+    #[cfg(feature = "use_std")]
+    fn group_by_indexed<K, F>(self, key: F) -> GroupByIndexed<K, Self, F> where
         Self: Sized,
-        Self::Item: Clone
+        F: FnMut(&Self::Item) -> K,
     {
-        tee::new(self)
+        GroupByIndexed::new(self, key)
     }
 
-    /// Return a sliced iterator.
+    /// Group consecutive elements that map to the same key into
+    /// **(key, Vec\<Self::Item\>)** pairs, buffering each run eagerly.
     ///
-    /// **Note:** slicing an iterator is not constant time, and much less efficient than
-    /// slicing for example a vector.
+    /// This is *.group_by()* with the key type first in the type of the
+    /// adaptor: since groups are owned vectors, there are no shared borrows
+    /// and dropping a group early has no effect on the others.
     ///
-    /// Iterator element type is **Self::Item**.
+    /// Iterator element type is **(K, Vec\<Self::Item\>)**
     ///
     /// ## Example
+    ///
     /// ```
-    /// use std::iter::repeat;
     /// use itertools::Itertools;
     ///
-    /// let it = repeat('a').slice(..3);
-    /// assert_eq!(it.count(), 3);
+    /// let chunks = vec![1, 1, 2, 2, 2, 3].into_iter()
+    ///                  .chunk_by_key(|&x| x)
+    ///                  .collect_vec();
+    /// assert_eq!(chunks, vec![(1, vec![1, 1]), (2, vec![2, 2, 2]), (3, vec![3])]);
     /// ```
-    fn slice<R>(self, range: R) -> ISlice<Self> where
-        R: misc::GenericRange,
+    #[cfg(feature = "use_std")]
+    fn chunk_by_key<K, F>(self, key: F) -> ChunkByKey<Self, K, F> where
         Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
     {
-        ISlice::new(self, range)
+        GroupBy::new(self, key)
     }
 
-    /// Return an iterator inside a **Rc\<RefCell\<_\>\>** wrapper.
+    /// Return an iterator adaptor that yields every window of **n**
+    /// consecutive elements, as a vector of clones of the elements.
     ///
-    /// The returned **RcIter** can be cloned, and each clone will refer back to the
-    /// same original iterator.
+    /// An iterator with fewer than **n** elements yields no windows.
     ///
-    /// **RcIter** allows doing interesting things like using **.zip()** on an iterator with
-    /// itself, at the cost of runtime borrow checking.
-    /// (If it is not obvious: this has a performance penalty.)
+    /// Iterator element type is **Vec\<Self::Item\>**.
     ///
-    /// Iterator element type is **Self::Item**.
+    /// **Panics** if **n** is zero.
     ///
     /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let mut rit = (0..9).into_rc();
-    /// let mut z = rit.clone().zip(rit.clone());
-    /// assert_eq!(z.next(), Some((0, 1)));
-    /// assert_eq!(z.next(), Some((2, 3)));
-    /// assert_eq!(z.next(), Some((4, 5)));
-    /// assert_eq!(rit.next(), Some(6));
-    /// assert_eq!(z.next(), Some((7, 8)));
-    /// assert_eq!(z.next(), None);
+    /// let windows = (1..5).windows(3).collect_vec();
+    /// assert_eq!(windows, vec![vec![1, 2, 3], vec![2, 3, 4]]);
     /// ```
-    ///
-    /// **Panics** in iterator methods if a borrow error is encountered,
-    /// but it can only happen if the RcIter is reentered in for example **.next()**,
-    /// i.e. if it somehow participates in an “iterator knot” where it is an adaptor of itself.
-    fn into_rc(self) -> RcIter<Self> where
+    #[cfg(feature = "use_std")]
+    fn windows(self, n: usize) -> Windows<Self> where
         Self: Sized,
+        Self::Item: Clone,
     {
-        RcIter::new(self)
+        Windows::new(self, n)
     }
 
-    /// Return an iterator adaptor that steps **n** elements in the base iterator
-    /// for each iteration.
+    /// Return an iterator adaptor that yields the elements in chunks of
+    /// **n**, each as a vector. The last chunk is shorter if the number of
+    /// elements is not a multiple of **n**.
     ///
-    /// The iterator steps by yielding the next element from the base iterator,
-    /// then skipping forward **n - 1** elements.
+    /// Chunks are pulled from the base iterator one at a time. The size hint
+    /// is the base iterator's divided by **n**, rounding up, so collecting
+    /// the chunks can preallocate.
     ///
-    /// Iterator element type is **Self::Item**.
+    /// **Panics** if **n** is zero.
     ///
-    /// **Panics** if the step is 0.
+    /// Iterator element type is **Vec\<Self::Item\>**.
     ///
     /// ## Example
+    ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let it = (0..8).step(3);
-    /// assert!(itertools::equal(it, vec![0, 3, 6]));
+    /// let chunks = (0..10).chunks(3);
+    /// assert_eq!(chunks.size_hint(), (4, Some(4)));
+    /// assert_eq!(chunks.collect_vec(),
+    ///            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8], vec![9]]);
     /// ```
-    fn step(self, n: usize) -> Step<Self> where
+    #[cfg(feature = "use_std")]
+    fn chunks(self, n: usize) -> Chunks<Self> where
         Self: Sized,
     {
-        Step::new(self, n)
+        Chunks::new(self, n)
     }
 
-    /// Return an iterator adaptor that merges the two base iterators in ascending order.
-    /// If both base iterators are sorted (ascending), the result is sorted.
+    /// Return an iterator adaptor that yields each pair of adjacent
+    /// elements, as **(previous, current)**.
     ///
-    /// Iterator element type is **Self::Item**.
+    /// An iterator with fewer than two elements yields no pairs.
+    ///
+    /// Iterator element type is **(Self::Item, Self::Item)**.
     ///
     /// ## Example
+    ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let a = (0..11).step(3);
-    /// let b = (0..11).step(5);
-    /// let it = a.merge(b);
-    /// assert!(itertools::equal(it, vec![0, 0, 3, 5, 6, 9, 10]));
+    /// let deltas = vec![10, 13, 9].into_iter().pairwise().map(|(a, b)| b - a);
+    /// assert!(itertools::equal(deltas, vec![3, -4]));
     /// ```
-    fn merge<J>(self, other: J) -> MergeAscend<Self, J::IntoIter> where
+    fn pairwise(self) -> Pairwise<Self> where
         Self: Sized,
-        Self::Item: PartialOrd,
-        J: IntoIterator<Item=Self::Item>,
+        Self::Item: Clone,
     {
-        fn wrapper<A: PartialOrd>(a: &A, b: &A) -> Ordering {
-            a.partial_cmp(b).unwrap_or(Ordering::Less)
-        };
-        self.merge_by(other, wrapper)
+        Pairwise::new(self)
     }
 
-    /// Return an iterator adaptor that merges the two base iterators in order.
-    /// This is much like *.merge()* but allows for a custom ordering.
+    /// Return an iterator adaptor that yields the running reduction of the
+    /// elements with **f**, seeded by the first element.
     ///
-    /// This can be especially useful for sequences of tuples.
+    /// The first element is yielded unchanged, and then each accumulated
+    /// value **f(&acc, elt)** in turn; this is a lazy *.fold1()* that emits
+    /// every intermediate result. The size hint is the base iterator's.
     ///
     /// Iterator element type is **Self::Item**.
     ///
     /// ## Example
+    ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let a = (0..).zip("bc".chars());
-    /// let b = (0..).zip("ad".chars());
-    /// let it = a.merge_by(b, |x, y| x.1.cmp(&y.1));
-    /// assert!(itertools::equal(it, vec![(0, 'a'), (0, 'b'), (1, 'c'), (1, 'd')]));
+    /// let sums = vec![1, 2, 3, 4].into_iter().scan1(|acc, x| acc + x);
+    /// assert!(itertools::equal(sums, vec![1, 3, 6, 10]));
     /// ```
-
-    fn merge_by<J, F>(self, other: J, cmp: F) -> Merge<Self, J::IntoIter, F> where
+    fn scan1<F>(self, f: F) -> Scan1<Self, F> where
         Self: Sized,
-        J: IntoIterator<Item=Self::Item>,
-        F: FnMut(&Self::Item, &Self::Item) -> Ordering
+        Self::Item: Clone,
+        F: FnMut(&Self::Item, Self::Item) -> Self::Item,
     {
-        Merge::new(self, other.into_iter(), cmp)
+        Scan1::new(self, f)
     }
 
-    /// Return an iterator adaptor that iterates over the cartesian product of
-    /// the element sets of two iterators **self** and **J**.
+    /// Split the iterator elements into segments. The predicate **pred** is
+    /// called with each pair of neighboring elements *a*, *b*; if it returns
+    /// **true**, *a* ends the current segment and *b* starts the next one.
     ///
-    /// Iterator element type is **(Self::Item, J::Item)**.
+    /// All elements are kept; an empty iterator yields no segments.
+    ///
+    /// Iterator element type is **Vec\<Self::Item\>**.
+    ///
+    /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let it = (0..2).cartesian_product("αβ".chars());
-    /// assert!(itertools::equal(it, vec![(0, 'α'), (0, 'β'), (1, 'α'), (1, 'β')]));
+    /// // split into increasing runs
+    /// let runs = vec![1, 2, 5, 3, 4, 0].into_iter()
+    ///                .split_when(|a, b| b < a)
+    ///                .collect_vec();
+    /// assert_eq!(runs, vec![vec![1, 2, 5], vec![3, 4], vec![0]]);
     /// ```
-    fn cartesian_product<J>(self, other: J) -> Product<Self, J::IntoIter> where
+    #[cfg(feature = "use_std")]
+    fn split_when<F>(self, pred: F) -> SplitWhen<Self, F> where
         Self: Sized,
-        Self::Item: Clone,
-        J: IntoIterator,
-        J::IntoIter: Clone,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
     {
-        Product::new(self, other.into_iter())
+        SplitWhen::new(self, pred)
     }
 
-    /// Return an iterator adaptor that enumerates the iterator elements,
-    /// starting from **start** and incrementing by one.
+    /// Split the iterator elements into segments separated by the elements
+    /// for which **pred** returns **true**. The separators are dropped.
     ///
-    /// Iterator element type is **(K, Self::Item)**.
+    /// An empty iterator yields no segments. Like with string splitting,
+    /// a leading, trailing or repeated separator yields an empty segment.
+    ///
+    /// Iterator element type is **Vec\<Self::Item\>**.
+    ///
+    /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// assert_eq!(
-    ///     "αβγ".chars().enumerate_from(-10i8).collect_vec(),
-    ///     [(-10, 'α'), (-9, 'β'), (-8, 'γ')]
-    /// );
+    /// let statements = "a=1;b=2;;".chars()
+    ///                      .split_on(|&c| c == ';')
+    ///                      .map(|s| s.into_iter().collect::<String>())
+    ///                      .collect_vec();
+    /// assert_eq!(statements, vec!["a=1", "b=2", "", ""]);
     /// ```
-    #[cfg(feature = "unstable")]
-    fn enumerate_from<K>(self, start: K) -> EnumerateFrom<Self, K> where
+    #[cfg(feature = "use_std")]
+    fn split_on<F>(self, pred: F) -> SplitOn<Self, F> where
         Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
     {
-        EnumerateFrom::new(self, start)
+        SplitOn::new(self, pred)
     }
 
-    /// Return an iterator adapter that allows peeking multiple values.
+    /// Return a **GroupingMap** that groups the **(key, value)** elements of
+    /// the iterator by key, and folds each group with one of its methods
+    /// like *.sum()*, *.min()*, *.max()*, *.count()* or *.fold()*.
     ///
-    /// After a call to *.next()* the peeking cursor is reset.
+    /// The values are aggregated as they arrive, without first collecting
+    /// each group into a vector.
     ///
     /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let nums = vec![1u8,2,3,4,5];
-    /// let mut peekable = nums.into_iter().multipeek();
-    /// assert_eq!(peekable.peek(), Some(&1));
-    /// assert_eq!(peekable.peek(), Some(&2));
-    /// assert_eq!(peekable.peek(), Some(&3));
-    /// assert_eq!(peekable.next(), Some(1));
-    /// assert_eq!(peekable.peek(), Some(&2));
+    /// let data = vec![(1, 2), (1, 3), (2, 10)];
+    /// let sums = data.into_iter().into_grouping_map().sum();
+    ///
+    /// assert_eq!(sums[&1], 5);
+    /// assert_eq!(sums[&2], 10);
     /// ```
-    fn multipeek(self) -> MultiPeek<Self> where
-        Self: Sized
+    #[cfg(feature = "use_std")]
+    fn into_grouping_map<K, V>(self) -> GroupingMap<Self> where
+        Self: Iterator<Item=(K, V)> + Sized,
+        K: Hash + Eq,
     {
-        MultiPeek::new(self)
+        GroupingMap::new(self)
     }
 
-    /// Return an iterator adaptor that uses the passed-in closure to
-    /// optionally merge together consecutive elements. For each pair the closure
-    /// is passed the latest two elements, `x`, `y` and may return either `Ok(z)`
-    /// to merge the two values or `Err((x, y))` to indicate they can't be merged.
+    /// Split into an iterator pair that both yield all elements from
+    /// the original iterator.
     ///
-    /// *.dedup()* and *.mend_slices()* are specializations of the coalesce
-    /// adaptor.
+    /// **Note:** If the iterator is clonable, prefer using that instead
+    /// of using this method. It is likely to be more efficient.
     ///
-    /// Iterator element type is **Self::Item**.
+    /// The elements that one half has yielded but the other has not are
+    /// buffered, so if one half runs far ahead of the other, the buffer
+    /// grows to hold all the elements in between.
     ///
-    /// This iterator is *fused*.
+    /// Iterator element type is **Self::Item**.
     ///
     /// ## Example
-    ///
     /// ```
     /// use itertools::Itertools;
+    /// let xs = vec![0, 1, 2, 3];
     ///
-    /// // sum same-sign runs together
-    /// let data = vec![-1., -2., -3., 3., 1., 0., -1.];
+    /// let (mut t1, mut t2) = xs.into_iter().tee();
+    /// assert_eq!(t1.next(), Some(0));
+    /// assert_eq!(t1.next(), Some(1));
+    /// assert_eq!(t2.next(), Some(0));
+    /// assert_eq!(t1.next(), Some(2));
+    /// assert_eq!(t1.next(), Some(3));
+    /// assert_eq!(t1.next(), None);
+    /// assert_eq!(t2.next(), Some(1));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn tee(self) -> (Tee<Self>, Tee<Self>) where
+        Self: Sized,
+        Self::Item: Clone
+    {
+        tee::new(self)
+    }
+
+    /// Split into **n** iterators that all yield all elements from
+    /// the original iterator.
+    ///
+    /// The handles share one buffer. An element is dropped from the buffer once
+    /// every live handle has read it, and a dropped handle no longer holds
+    /// back the others.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut ts = (0..3).tee_n(3);
+    /// assert_eq!(ts[0].next(), Some(0));
+    /// assert_eq!(ts[1].next(), Some(0));
+    /// assert_eq!(ts[0].next(), Some(1));
+    /// assert!(itertools::equal(ts.pop().unwrap(), 0..3));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn tee_n(self, n: usize) -> Vec<TeeHandle<Self>> where
+        Self: Sized,
+        Self::Item: Clone
+    {
+        tee::new_n(self, n)
+    }
+
+    /// Return a handle that memoizes the elements of the iterator, so that
+    /// its clones can each iterate them independently.
+    ///
+    /// Elements are pulled from the iterator the first time any handle
+    /// needs them and stored in a buffer shared by all handles; handles that
+    /// are behind replay them from the buffer. Unlike *.tee()*, no handle
+    /// holds back another, and unlike collecting, nothing is computed before
+    /// it is needed. The buffer is freed with the last handle.
+    ///
+    /// A clone continues from the position of the handle it was cloned
+    /// from; *.replay()* returns a handle at the start.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut a = (0..5).map(|x| x * x).cached();
+    /// let b = a.clone();
+    /// assert_eq!(a.next(), Some(0));
+    /// assert_eq!(a.next(), Some(1));
+    /// assert!(itertools::equal(b, vec![0, 1, 4, 9, 16]));
+    /// assert!(itertools::equal(a.replay(), vec![0, 1, 4, 9, 16]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn cached(self) -> Cached<Self> where
+        Self: Sized,
+        Self::Item: Clone
+    {
+        Cached::new(self)
+    }
+
+    /// Return a sliced iterator.
+    ///
+    /// **Note:** slicing an iterator is not constant time, and much less efficient than
+    /// slicing for example a vector.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    /// ```
+    /// use std::iter::repeat;
+    /// use itertools::Itertools;
+    ///
+    /// let it = repeat('a').slice(..3);
+    /// assert_eq!(it.count(), 3);
+    /// ```
+    fn slice<R>(self, range: R) -> ISlice<Self> where
+        R: misc::GenericRange,
+        Self: Sized,
+    {
+        ISlice::new(self, range)
+    }
+
+    /// Return an iterator over the elements whose indices fall within
+    /// **range**.
+    ///
+    /// Works like *.slice()*, but accepts any **RangeBounds\<usize\>** value,
+    /// including inclusive ranges and ranges with an excluded start. If the
+    /// range extends past the end of the iterator, fewer elements are
+    /// yielded; a reversed range yields nothing.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert!(itertools::equal((0..10).get(2..5), vec![2, 3, 4]));
+    /// assert!(itertools::equal((0..10).get(7..), vec![7, 8, 9]));
+    /// assert!(itertools::equal((0..10).get(..=1), vec![0, 1]));
+    /// ```
+    fn get<R>(self, range: R) -> ISlice<Self> where
+        R: RangeBounds<usize>,
+        Self: Sized,
+    {
+        ISlice::from_range_bounds(self, range)
+    }
+
+    /// Return an iterator inside a **Rc\<RefCell\<_\>\>** wrapper.
+    ///
+    /// The returned **RcIter** can be cloned, and each clone will refer back to the
+    /// same original iterator.
+    ///
+    /// **RcIter** allows doing interesting things like using **.zip()** on an iterator with
+    /// itself, at the cost of runtime borrow checking.
+    /// (If it is not obvious: this has a performance penalty.)
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut rit = (0..9).into_rc();
+    /// let mut z = rit.clone().zip(rit.clone());
+    /// assert_eq!(z.next(), Some((0, 1)));
+    /// assert_eq!(z.next(), Some((2, 3)));
+    /// assert_eq!(z.next(), Some((4, 5)));
+    /// assert_eq!(rit.next(), Some(6));
+    /// assert_eq!(z.next(), Some((7, 8)));
+    /// assert_eq!(z.next(), None);
+    /// ```
+    ///
+    /// **Panics** in iterator methods if a borrow error is encountered,
+    /// but it can only happen if the RcIter is reentered in for example **.next()**,
+    /// i.e. if it somehow participates in an “iterator knot” where it is an adaptor of itself.
+    #[cfg(feature = "use_std")]
+    fn into_rc(self) -> RcIter<Self> where
+        Self: Sized,
+    {
+        RcIter::new(self)
+    }
+
+    /// Return an iterator adaptor that steps **n** elements in the base iterator
+    /// for each iteration.
+    ///
+    /// The iterator steps by yielding the next element from the base iterator,
+    /// then skipping forward **n - 1** elements, so the first element is
+    /// always included. The size hint is exact when the base iterator's is,
+    /// and the adaptor is **Clone** when the base iterator is.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// **Panics** if the step is 0.
+    ///
+    /// ## Example
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..8).step(3);
+    /// assert!(itertools::equal(it, vec![0, 3, 6]));
+    /// ```
+    fn step(self, n: usize) -> Step<Self> where
+        Self: Sized,
+    {
+        Step::new(self, n)
+    }
+
+    /// Return an iterator adaptor that merges the two base iterators in ascending order.
+    /// If both base iterators are sorted (ascending), the result is sorted.
+    ///
+    /// The rest of both base iterators can be recovered at any point with
+    /// *Merge::into_parts()*.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = (0..11).step(3);
+    /// let b = (0..11).step(5);
+    /// let it = a.merge(b);
+    /// assert!(itertools::equal(it, vec![0, 0, 3, 5, 6, 9, 10]));
+    /// ```
+    fn merge<J>(self, other: J) -> MergeAscend<Self, J::IntoIter> where
+        Self: Sized,
+        Self::Item: PartialOrd,
+        J: IntoIterator<Item=Self::Item>,
+    {
+        fn wrapper<A: PartialOrd>(a: &A, b: &A) -> Ordering {
+            a.partial_cmp(b).unwrap_or(Ordering::Less)
+        };
+        self.merge_by(other, wrapper)
+    }
+
+    /// Return an iterator adaptor that merges the two base iterators in order.
+    /// This is much like *.merge()* but allows for a custom ordering.
+    ///
+    /// This can be especially useful for sequences of tuples.
+    ///
+    /// The merge is stable: **cmp** is called with an element from this
+    /// iterator first, and when it returns **Equal** that element is taken
+    /// first.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = (0..).zip("bc".chars());
+    /// let b = (0..).zip("ad".chars());
+    /// let it = a.merge_by(b, |x, y| x.1.cmp(&y.1));
+    /// assert!(itertools::equal(it, vec![(0, 'a'), (0, 'b'), (1, 'c'), (1, 'd')]));
+    /// ```
+
+    fn merge_by<J, F>(self, other: J, cmp: F) -> Merge<Self, J::IntoIter, F> where
+        Self: Sized,
+        J: IntoIterator<Item=Self::Item>,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering
+    {
+        Merge::new(self, other.into_iter(), cmp)
+    }
+
+    /// Return an iterator adaptor that flattens an iterator of iterators by
+    /// merging them in ascending order.
+    ///
+    /// If all base iterators are sorted (ascending), the result is sorted.
+    ///
+    /// The base iterators are kept in a binary heap, so producing each element
+    /// takes *O(log k)* time where *k* is the number of base iterators.
+    /// The relative order of equal elements from different base iterators
+    /// is unspecified.
+    ///
+    /// Iterator element type is **Self::Item::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = (0..11).step(3);
+    /// let b = (0..11).step(5);
+    /// let c = (0..11).step(10);
+    /// let it = vec![a, b, c].into_iter().kmerge();
+    /// assert!(itertools::equal(it, vec![0, 0, 0, 3, 5, 6, 9, 10, 10]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn kmerge(self) -> KMerge<<Self::Item as IntoIterator>::IntoIter> where
+        Self: Sized,
+        Self::Item: IntoIterator,
+        <Self::Item as IntoIterator>::Item: PartialOrd,
+    {
+        fn less_than<T: PartialOrd>(a: &T, b: &T) -> bool {
+            a < b
+        }
+        KMergeBy::new(self, less_than)
+    }
+
+    /// Return an iterator adaptor that flattens an iterator of iterators by
+    /// merging them according to the given closure.
+    ///
+    /// The closure **first** is called with two elements *a*, *b* and should
+    /// return **true** if *a* is ordered before *b*.
+    ///
+    /// If all base iterators are sorted according to **first**, the result is
+    /// sorted.
+    ///
+    /// Iterator element type is **Self::Item::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let a = vec![-1f64, 2., 3., -5., 6., -7.];
+    /// let b = vec![0., 2., -4.];
+    /// let mut it = vec![a, b].into_iter().kmerge_by(|a, b| a.abs() < b.abs());
+    /// assert_eq!(it.next(), Some(0.));
+    /// assert_eq!(it.last(), Some(-7.));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn kmerge_by<F>(self, first: F) -> KMergeBy<<Self::Item as IntoIterator>::IntoIter, F> where
+        Self: Sized,
+        Self::Item: IntoIterator,
+        F: FnMut(&<Self::Item as IntoIterator>::Item,
+                 &<Self::Item as IntoIterator>::Item) -> bool,
+    {
+        KMergeBy::new(self, first)
+    }
+
+    /// Return an iterator adaptor that iterates over the cartesian product of
+    /// the element sets of two iterators **self** and **J**.
+    ///
+    /// The product is double ended if both iterators are.
+    ///
+    /// Iterator element type is **(Self::Item, J::Item)**.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..2).cartesian_product("αβ".chars());
+    /// assert!(itertools::equal(it.clone(), vec![(0, 'α'), (0, 'β'), (1, 'α'), (1, 'β')]));
+    /// assert!(itertools::equal(it.rev(), vec![(1, 'β'), (1, 'α'), (0, 'β'), (0, 'α')]));
+    /// ```
+    fn cartesian_product<J>(self, other: J) -> Product<Self, J::IntoIter> where
+        Self: Sized,
+        Self::Item: Clone,
+        J: IntoIterator,
+        J::IntoIter: Clone,
+    {
+        Product::new(self, other.into_iter())
+    }
+
+    /// Return an iterator adaptor that iterates over the pairs of the
+    /// cartesian product of **self** and **J** for which **keep** returns
+    /// **true**.
+    ///
+    /// This is like *.cartesian_product()* followed by *.filter()*, but
+    /// **keep** is given references to both elements, and a pair (with its
+    /// clone of **self**'s element) is only built once it is accepted.
+    ///
+    /// The size hint has a lower bound of zero, and the upper bound of the
+    /// whole product.
+    ///
+    /// Iterator element type is **(Self::Item, J::Item)**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..4).filtered_cartesian_product(0..4, |a, b| a < b);
+    /// assert!(itertools::equal(it, vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]));
+    /// ```
+    fn filtered_cartesian_product<J, F>(self, other: J, keep: F)
+        -> FilteredProduct<Self, J::IntoIter, F> where
+        Self: Sized,
+        Self::Item: Clone,
+        J: IntoIterator,
+        J::IntoIter: Clone,
+        F: FnMut(&Self::Item, &J::Item) -> bool,
+    {
+        FilteredProduct::new(self, other.into_iter(), keep)
+    }
+
+    /// Return an iterator adaptor that iterates over the **n**-fold cartesian
+    /// product of the elements of **self** with itself, as vectors of length
+    /// **n**.
+    ///
+    /// The vectors come in lexicographic ("odometer") order of the element
+    /// positions, the last position changing fastest. The elements of
+    /// **self** are buffered as they are pulled, so **self** is iterated
+    /// only once. For **n == 0** a single empty vector is produced, even if
+    /// **self** is empty.
+    ///
+    /// Iterator element type is **Vec\<Self::Item\>**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = "ab".chars().cartesian_power(2);
+    /// itertools::assert_equal(it, vec![vec!['a', 'a'], vec!['a', 'b'],
+    ///                                  vec!['b', 'a'], vec!['b', 'b']]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn cartesian_power(self, n: usize) -> CartesianPower<Self> where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        CartesianPower::new(self, n)
+    }
+
+    /// Return an iterator adaptor that flattens elements like
+    /// **((A, B), C)** into **(A, B, C)**, for tuples of up to 12 elements
+    /// on the left.
+    ///
+    /// This is useful after chaining *.zip()* or *.cartesian_product()*.
+    /// One level of nesting is removed per application; the adaptor is
+    /// double ended and has an exact size if the iterator does.
+    ///
+    /// Iterator element type is **(A, B, ..., X)** if **Self::Item** is
+    /// **((A, B, ...), X)**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (0..2).zip("ab".chars()).zip(vec![true, false]).cons_tuples();
+    /// assert!(itertools::equal(it, vec![(0, 'a', true), (1, 'b', false)]));
+    /// ```
+    fn cons_tuples<T, X>(self) -> ConsTuples<Self> where
+        Self: Sized + Iterator<Item=(T, X)>,
+        T: misc::AppendTuple<X>,
+    {
+        misc::FlatTuples::new(self)
+    }
+
+    /// Return an iterator adaptor that enumerates the iterator elements,
+    /// starting from **start** and incrementing by one.
+    ///
+    /// Like *.enumerate()* with an offset. The index saturates at
+    /// **usize::MAX**. If the iterator is double ended and has an exact
+    /// size, the indices can also be taken from the back.
+    ///
+    /// Iterator element type is **(usize, Self::Item)**.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert!(itertools::equal(
+    ///     "αβγ".chars().enumerate_from(100),
+    ///     vec![(100, 'α'), (101, 'β'), (102, 'γ')]
+    /// ));
+    /// assert_eq!(['a', 'b'].iter().enumerate_from(100).next_back(), Some((101, &'b')));
+    /// ```
+    fn enumerate_from(self, start: usize) -> EnumerateFrom<Self> where
+        Self: Sized,
+    {
+        EnumerateFrom::new(self, start)
+    }
+
+    /// Return an iterator adapter that allows peeking multiple values.
+    ///
+    /// After a call to *.next()* or *.reset_peek()* the peeking cursor is reset.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let nums = vec![1u8,2,3,4,5];
+    /// let mut peekable = nums.into_iter().multipeek();
+    /// assert_eq!(peekable.peek(), Some(&1));
+    /// assert_eq!(peekable.peek(), Some(&2));
+    /// assert_eq!(peekable.peek(), Some(&3));
+    /// assert_eq!(peekable.next(), Some(1));
+    /// assert_eq!(peekable.peek(), Some(&2));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn multipeek(self) -> MultiPeek<Self> where
+        Self: Sized
+    {
+        MultiPeek::new(self)
+    }
+
+    /// Return an iterator adaptor that can peek at the back element of a
+    /// double ended iterator with *.peek_back()*.
+    ///
+    /// See [*peekable_back()*](fn.peekable_back.html) for more information.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut it = "abc".chars().peekable_back();
+    /// assert_eq!(it.peek_back(), Some(&'c'));
+    /// assert!(itertools::equal(it, "abc".chars()));
+    /// ```
+    fn peekable_back(self) -> PeekBack<Self> where
+        Self: Sized + DoubleEndedIterator,
+    {
+        peekable_back(self)
+    }
+
+    /// Return an iterator adaptor that uses the passed-in closure to
+    /// optionally merge together consecutive elements. For each pair the closure
+    /// is passed the latest two elements, `x`, `y` and may return either `Ok(z)`
+    /// to merge the two values or `Err((x, y))` to indicate they can't be merged.
+    ///
+    /// *.dedup()* and *.mend_slices()* are specializations of the coalesce
+    /// adaptor.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// // sum same-sign runs together
+    /// let data = vec![-1., -2., -3., 3., 1., 0., -1.];
     /// assert!(itertools::equal(data.into_iter().coalesce(|x, y|
     ///         if (x >= 0.) == (y >= 0.) {
     ///             Ok(x + y)
@@ -565,380 +1408,1757 @@ pub trait Itertools : Iterator {
     ///         }),
     ///         vec![-6., 4., -1.]));
     /// ```
-    fn coalesce<F>(self, f: F) -> Coalesce<Self, F> where
-        Self: Sized,
-        F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, (Self::Item, Self::Item)>
+    fn coalesce<F>(self, f: F) -> Coalesce<Self, F> where
+        Self: Sized,
+        F: FnMut(Self::Item, Self::Item) -> Result<Self::Item, (Self::Item, Self::Item)>
+    {
+        Coalesce::new(self, f)
+    }
+
+    /// Remove duplicates from sections of consecutive identical elements.
+    /// If the iterator is sorted, all elements will be unique.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// This iterator is *fused*, and double ended if the base iterator is.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1., 1., 2., 3., 3., 2., 2.];
+    /// assert!(itertools::equal(data.into_iter().dedup(),
+    ///                          vec![1., 2., 3., 2.]));
+    /// ```
+    fn dedup(self) -> Dedup<Self> where
+        Self: Sized,
+        Self::Item: PartialEq,
+    {
+        Dedup::new(self)
+    }
+
+    /// Remove duplicates from sections of consecutive elements that have
+    /// equal keys, as computed by **key**.
+    ///
+    /// The first element of each run is kept. The key function is called
+    /// exactly once per element; the key of the last kept element is
+    /// cached for comparison.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec!["apple", "avocado", "banana", "blueberry", "apricot"];
+    /// assert!(itertools::equal(data.into_iter().dedup_by_key(|s| s.chars().next()),
+    ///                          vec!["apple", "banana", "apricot"]));
+    /// ```
+    fn dedup_by_key<F, K>(self, key: F) -> DedupByKey<Self, F, K> where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> K,
+        K: PartialEq,
+    {
+        DedupByKey::new(self, key)
+    }
+
+    /// Remove consecutive elements that **same** considers the same, and
+    /// yield each remaining element with the length of its run.
+    ///
+    /// Each run is represented by its first element, and **same** is called
+    /// with that first element and each following candidate.
+    ///
+    /// Iterator element type is **(usize, Self::Item)**.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1.1f64, 1.9, 2.2, 3.0, 3.5, 3.9];
+    /// let runs = data.into_iter().dedup_by_with_count(|a, b| a.floor() == b.floor());
+    /// assert!(itertools::equal(runs, vec![(2, 1.1), (1, 2.2), (3, 3.0)]));
+    /// ```
+    fn dedup_by_with_count<F>(self, same: F) -> DedupByWithCount<Self, F> where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> bool,
+    {
+        DedupByWithCount::new(self, same)
+    }
+
+    /// Return an iterator adaptor that filters out elements whose key has
+    /// already been produced once during the iteration.
+    ///
+    /// The key is borrowed from the element by **f**; its owned form (see
+    /// **ToOwned**) is stored in a hash set the first time it is seen.
+    /// Duplicates are only looked up, so they cost no allocation or clone.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec!["a".to_string(), "b".to_string(), "a".to_string()];
+    /// let unique = data.into_iter().unique_by_owned(|s: &String| s.as_str());
+    /// assert!(itertools::equal(unique, vec!["a".to_string(), "b".to_string()]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn unique_by_owned<K: ?Sized, F>(self, f: F) -> UniqueByOwned<Self, F, K> where
+        Self: Sized,
+        F: for<'a> FnMut(&'a Self::Item) -> &'a K,
+        K: ToOwned + Hash + Eq,
+        K::Owned: Hash + Eq,
+    {
+        UniqueByOwned::new(self, f)
+    }
+
+
+    /// Return an iterator adaptor that joins together adjacent slices if possible.
+    ///
+    /// Only implemented for iterators with slice or string slice elements.
+    /// Only slices that are contiguous together can be joined.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let text = String::from("let there be text");
+    /// let excerpts = vec![&text[0..4], &text[4..9], &text[10..12], &text[12..]];
+    ///
+    /// assert!(itertools::equal(excerpts.into_iter().mend_slices(),
+    ///                          vec!["let there", "be text"]));
+    /// ```
+    fn mend_slices(self) -> CoalesceFn<Self> where
+        Self: Sized,
+        Self::Item: misc::MendSlice
+    {
+        fn mend<T: misc::MendSlice>(x: T, y: T) -> Result<T, (T, T)>
+        {
+            match misc::MendSlice::mend(x, y) {
+                Some(z) => Ok(z),
+                None => Err((x, y)),
+            }
+        }
+        Coalesce::new(self, mend)
+    }
+
+    /// Return an iterator adaptor that borrows from a **Clone**-able iterator
+    /// to only pick off elements while the predicate **f** returns **true**.
+    ///
+    /// It uses the **Clone** trait to restore the original iterator so that the last
+    /// and rejected element is still available when **TakeWhileRef** is done.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut alphanumerics = "abcdef012345".chars();
+    ///
+    /// let alphas = alphanumerics.take_while_ref(|c| c.is_alphabetic())
+    ///                           .collect::<String>();
+    /// assert_eq!(alphas, "abcdef");
+    /// assert_eq!(alphanumerics.next(), Some('0'));
+    ///
+    /// ```
+    fn take_while_ref<'a, F>(&'a mut self, f: F) -> TakeWhileRef<'a, Self, F> where
+        Self: Clone,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhileRef::new(self, f)
+    }
+
+    /// Return an iterator adaptor that borrows from this iterator and
+    /// takes items while the closure **accept** returns **true**.
+    ///
+    /// This adaptor can only be used on iterators that implement **PeekingNext**
+    /// like **Peekable**, **PutBack**, **PutBackN**, **MultiPeek** and a few other collection
+    /// iterators.
+    ///
+    /// The last and rejected element (first **false**) is still available when
+    /// **.peeking_take_while()** is done.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut it = (0..10).peekable();
+    /// let small = it.peeking_take_while(|&x| x < 3).collect::<Vec<_>>();
+    /// assert_eq!(small, vec![0, 1, 2]);
+    /// assert_eq!(it.next(), Some(3));
+    /// ```
+    fn peeking_take_while<F>(&mut self, accept: F) -> PeekingTakeWhile<'_, Self, F> where
+        Self: Sized + PeekingNext,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        PeekingTakeWhile::new(self, accept)
+    }
+
+    /// Return an iterator adaptor that yields elements while the predicate
+    /// **f** returns **true**, and then also the first element for which it
+    /// returns **false**, before stopping.
+    ///
+    /// Unlike *.take_while()*, the element that ends the run is not lost.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = vec![1, 2, 0, 3, 4].into_iter().take_while_inclusive(|&x| x != 0);
+    /// assert!(itertools::equal(it, vec![1, 2, 0]));
+    /// ```
+    fn take_while_inclusive<F>(self, f: F) -> TakeWhileInclusive<Self, F> where
+        Self: Sized,
+        F: FnMut(&Self::Item) -> bool,
+    {
+        TakeWhileInclusive::new(self, f)
+    }
+
+    /// Return an iterator adaptor that iterates over the combinations of
+    /// the elements from an iterator.
+    ///
+    /// Iterator element type is **(Self::Item, Self::Item)**.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (1..5).combinate_pairs();
+    /// assert!(itertools::equal(it, vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]));
+    /// ```
+    fn combinations(self) -> Combinations<Self> where
+        Self: Sized + Clone, Self::Item: Clone
+    {
+        Combinations::new(self)
+    }
+
+    /// Return an iterator adaptor that iterates over the combinations of the
+    /// elements from an iterator, yielded as tuples of size **T**.
+    ///
+    /// Tuples of size 1 to 12 are supported. The source iterator is buffered
+    /// in full when the first combination is requested; from then on the
+    /// size hint is exact.
+    ///
+    /// Iterator element type is **T**.
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = (1..5).tuple_combinations::<(_, _)>();
+    /// assert!(itertools::equal(it, vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]));
+    ///
+    /// let it = (1..5).tuple_combinations::<(_, _, _)>();
+    /// assert!(itertools::equal(it, vec![(1, 2, 3), (1, 2, 4), (1, 3, 4), (2, 3, 4)]));
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn tuple_combinations<T>(self) -> TupleCombinations<Self, T> where
+        Self: Sized,
+        Self::Item: Clone,
+        T: misc::TupleCollect<Item=Self::Item>,
+    {
+        TupleCombinations::new(self)
+    }
+
+    /// Like regular *.map()*, specialized to using a simple function pointer instead,
+    /// so that the resulting **Map** iterator value can be cloned.
+    ///
+    /// Iterator element type is **B**.
+    fn map_fn<B>(self, f: fn(Self::Item) -> B) -> MapFn<Self, B> where
+        Self: Sized
+    {
+        self.map(f)
+    }
+
+    /// Convert each element of the iterator using **Into**, like
+    /// *.map(Into::into)*, but with the target type given on the adaptor.
+    ///
+    /// Iterator element type is **R**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let names = vec!["a", "b"].into_iter().map_into::<String>().collect::<Vec<_>>();
+    /// assert_eq!(names, vec![String::from("a"), String::from("b")]);
+    /// ```
+    fn map_into<R>(self) -> MapInto<Self, R> where
+        Self: Sized,
+        Self::Item: Into<R>,
+    {
+        MapInto::new(self)
+    }
+
+    /// Return an iterator adaptor that yields the indices of all elements
+    /// satisfying a predicate, counted from the start of the iterator.
+    ///
+    /// If the iterator is double ended and has an exact size, the indices
+    /// can also be taken from the back.
+    ///
+    /// Iterator element type is **usize**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 2, 3, 3, 4, 6, 7, 9];
+    /// assert!(itertools::equal(data.iter().positions(|v| v % 2 == 0),
+    ///                          vec![1, 4, 5]));
+    ///
+    /// assert_eq!(data.iter().positions(|v| v % 3 == 0).next_back(), Some(7));
+    /// ```
+    fn positions<P>(self, predicate: P) -> Positions<Self, P> where
+        Self: Sized,
+        P: FnMut(Self::Item) -> bool,
+    {
+        Positions::new(self, predicate)
+    }
+
+    /// Return an iterator adaptor that yields the index range of each
+    /// maximal run of consecutive elements satisfying a predicate, counted
+    /// from the start of the iterator.
+    ///
+    /// Iterator element type is **Range\<usize\>**.
+    ///
+    /// This iterator is *fused*.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![0, 1, 1, 0, 1];
+    /// assert!(itertools::equal(data.iter().position_runs(|&x| x == 1),
+    ///                          vec![1..3, 4..5]));
+    /// ```
+    fn position_runs<P>(self, predicate: P) -> PositionRuns<Self, P> where
+        Self: Sized,
+        P: FnMut(Self::Item) -> bool,
+    {
+        PositionRuns::new(self, predicate)
+    }
+
+    /// Return an iterator adaptor that applies **f** to each **Ok** value
+    /// and flattens the resulting iterables, while **Err** values are passed
+    /// through unchanged, in their original position.
+    ///
+    /// Iterator element type is **Result\<U::Item, E\>**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok(2), Err("x"), Ok(3)];
+    /// let it = input.into_iter().flat_map_ok(|n| 0..n);
+    /// itertools::assert_equal(it, vec![Ok(0), Ok(1), Err("x"), Ok(0), Ok(1), Ok(2)]);
+    /// ```
+    fn flat_map_ok<T, E, U, F>(self, f: F) -> FlatMapOk<Self, F, U> where
+        Self: Sized + Iterator<Item=Result<T, E>>,
+        F: FnMut(T) -> U,
+        U: IntoIterator,
+    {
+        FlatMapOk::new(self, f)
+    }
+
+    /// Return an iterator adaptor that applies **f** to each **Ok** value and
+    /// keeps only those mapped to **Some**, while **Err** values are always
+    /// passed through unchanged.
+    ///
+    /// This is *.filter_map()* for iterators of **Result**s.
+    ///
+    /// Iterator element type is **Result\<U, E\>**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok(1), Ok(2), Err("e"), Ok(3)];
+    /// let it = input.into_iter().filter_map_ok(|n| if n % 2 == 1 { Some(n * 10) } else { None });
+    /// itertools::assert_equal(it, vec![Ok(10), Err("e"), Ok(30)]);
+    /// ```
+    fn filter_map_ok<T, E, U, F>(self, f: F) -> FilterMapOk<Self, F> where
+        Self: Sized + Iterator<Item=Result<T, E>>,
+        F: FnMut(T) -> Option<U>,
+    {
+        FilterMapOk::new(self, f)
+    }
+
+    /// Return an iterator adaptor that flattens a stream of
+    /// **Result\<Option\<T\>, E\>**: **Ok(Some(x))** becomes **Ok(x)**,
+    /// **Ok(None)** is dropped, and **Err** values are passed through.
+    ///
+    /// This is *.filter_map_ok()* with the identity function, for layered
+    /// producers where both failure and absence are possible.
+    ///
+    /// Iterator element type is **Result\<T, E\>**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let input = vec![Ok(Some(1)), Ok(None), Err("x"), Ok(Some(2))];
+    /// let it = input.into_iter().flatten_result_options();
+    /// itertools::assert_equal(it, vec![Ok(1), Err("x"), Ok(2)]);
+    /// ```
+    fn flatten_result_options<T, E>(self) -> FlattenResultOptions<Self, T> where
+        Self: Sized + Iterator<Item=Result<Option<T>, E>>,
+    {
+        fn identity<T>(x: Option<T>) -> Option<T> {
+            x
+        }
+        self.filter_map_ok(identity)
+    }
+
+    /// **Deprecated:** Use *.map_fn()* instead.
+    fn fn_map<B>(self, map: fn(Self::Item) -> B) -> FnMap<B, Self> where
+        Self: Sized
+    {
+        FnMap::new(self, map)
+    }
+
+
+    // non-adaptor methods
+
+    /// Find the position and value of the first element satisfying a predicate.
+    fn find_position<P>(&mut self, mut pred: P) -> Option<(usize, Self::Item)> where
+        P: FnMut(&Self::Item) -> bool,
+    {
+        let mut index = 0usize;
+        for elt in self {
+            if pred(&elt) {
+                return Some((index, elt))
+            }
+            index += 1;
+        }
+        None
+    }
+
+    /// Find the first element satisfying a predicate, or else the last
+    /// element of the iterator.
+    ///
+    /// Return **None** if the iterator is empty. The iterator is consumed up
+    /// to and including the first match.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 2, 3, 4];
+    /// assert_eq!(data.iter().find_or_last(|&&x| x > 2), Some(&3));
+    /// assert_eq!(data.iter().find_or_last(|&&x| x > 10), Some(&4));
+    /// assert_eq!((0..0).find_or_last(|&x| x > 10), None);
+    /// ```
+    fn find_or_last<P>(self, mut pred: P) -> Option<Self::Item> where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        let mut last = None;
+        for elt in self {
+            if pred(&elt) {
+                return Some(elt);
+            }
+            last = Some(elt);
+        }
+        last
+    }
+
+    /// Find the first element satisfying a predicate, or else the first
+    /// element of the iterator.
+    ///
+    /// Return **None** if the iterator is empty. The iterator is consumed up
+    /// to and including the first match, or entirely if nothing matches.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 2, 3, 4];
+    /// assert_eq!(data.iter().find_or_first(|&&x| x > 2), Some(&3));
+    /// assert_eq!(data.iter().find_or_first(|&&x| x > 10), Some(&1));
+    /// assert_eq!((0..0).find_or_first(|&x| x > 10), None);
+    /// ```
+    fn find_or_first<P>(mut self, mut pred: P) -> Option<Self::Item> where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool,
+    {
+        let first = match self.next() {
+            None => return None,
+            Some(elt) => elt,
+        };
+        if pred(&first) {
+            return Some(first);
+        }
+        match self.find(pred) {
+            None => Some(first),
+            found => found,
+        }
+    }
+
+    /// Consume the first **n** elements of the iterator eagerly.
+    ///
+    /// Return actual number of elements consumed,
+    /// until done or reaching the end.
+    fn dropn(&mut self, mut n: usize) -> usize
+    {
+        let start = n;
+        while n > 0 {
+            match self.next() {
+                Some(..) => n -= 1,
+                None => break
+            }
+        }
+        start - n
+    }
+
+    /// Consume the first **n** elements from the iterator eagerly,
+    /// and return the same iterator again.
+    ///
+    /// It works similarly to **.skip(n)** except it is eager and
+    /// preserves the iterator type.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut iter = "αβγ".chars().dropping(2);
+    /// assert_eq!(iter.next(), Some('γ'));
+    /// assert_eq!(iter.next(), None);
+    /// ```
+    fn dropping(mut self, n: usize) -> Self where
+        Self: Sized,
+    {
+        self.dropn(n);
+        self
+    }
+
+    /// Consume the last **n** elements from the iterator eagerly,
+    /// and return the same iterator again.
+    ///
+    /// This is only possible on double ended iterators. **n** may be
+    /// larger than the number of elements.
+    ///
+    /// Note: This method is eager, dropping the back elements immediately and
+    /// preserves the iterator type.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let init = vec![0, 3, 6, 9].into_iter().dropping_back(1);
+    /// assert!(itertools::equal(init, vec![0, 3, 6]));
+    /// ```
+    fn dropping_back(mut self, n: usize) -> Self where
+        Self: Sized,
+        Self: DoubleEndedIterator,
+    {
+        self.by_ref().rev().dropn(n);
+        self
+    }
+
+    /// Run the closure **f** eagerly on each element of the iterator.
+    ///
+    /// Consumes the iterator until its end.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::sync::mpsc::channel;
+    /// use itertools::Itertools;
+    ///
+    /// let (tx, rx) = channel();
+    ///
+    /// // use .foreach() to apply a function to each value -- sending it
+    /// (0..5).map(|x| x * 2 + 1).foreach(|x| { tx.send(x).unwrap(); } );
+    ///
+    /// drop(tx);
+    ///
+    /// assert!(itertools::equal(rx.iter(), vec![1, 3, 5, 7, 9]));
+    /// ```
+    fn foreach<F>(&mut self, mut f: F) where
+        F: FnMut(Self::Item),
+    {
+        for elt in self { f(elt) }
+    }
+
+    /// **.collect_vec()** is simply a type specialization of **.collect()**,
+    /// for convenience.
+    #[cfg(feature = "use_std")]
+    fn collect_vec(self) -> Vec<Self::Item> where
+        Self: Sized,
+    {
+        self.collect()
+    }
+
+    /// Collect all elements into a **Vec**, sort them and return an iterator
+    /// of the sorted elements.
+    ///
+    /// The sort is stable: equal elements keep their relative order.
+    /// The returned iterator is double ended and has an exact size, so
+    /// *.sorted().rev()* iterates in descending order.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let top = vec![3, 1, 4, 1, 5, 9, 2].into_iter().sorted().rev().take(3);
+    /// itertools::assert_equal(top, vec![9, 5, 4]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted(self) -> SortedIter<Self::Item> where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut v = collect_reserved(self);
+        v.sort();
+        SortedIter::new(v)
+    }
+
+    /// Collect all elements into a **Vec**, sort them in descending order
+    /// and return an iterator of the sorted elements.
+    ///
+    /// The sort is stable: equal elements keep their relative order, unlike
+    /// with *.sorted().rev()*, which reverses them too.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// itertools::assert_equal(vec![3, 1, 2].into_iter().rev_sorted(), vec![3, 2, 1]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn rev_sorted(self) -> SortedIter<Self::Item> where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut v = collect_reserved(self);
+        v.sort_by(|a, b| b.cmp(a));
+        SortedIter::new(v)
+    }
+
+    /// Collect all elements into a **Vec**, sort them using the comparison
+    /// function **cmp** and return an iterator of the sorted elements.
+    ///
+    /// The sort is stable: equal elements keep their relative order.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = vec![(1, 'a'), (0, 'b'), (1, 'c')].into_iter()
+    ///              .sorted_by(|a, b| a.0.cmp(&b.0));
+    /// itertools::assert_equal(it, vec![(0, 'b'), (1, 'a'), (1, 'c')]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_by<F>(self, cmp: F) -> SortedIter<Self::Item> where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let mut v = collect_reserved(self);
+        v.sort_by(cmp);
+        SortedIter::new(v)
+    }
+
+    /// Collect all elements into a **Vec**, sort them by the key extracted
+    /// with **key** and return an iterator of the sorted elements.
+    ///
+    /// The sort is stable: elements with equal keys keep their relative
+    /// order.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = vec!["bb", "a", "cc", "d"].into_iter().sorted_by_key(|s| s.len());
+    /// itertools::assert_equal(it, vec!["a", "d", "bb", "cc"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_by_key<K, F>(self, key: F) -> SortedIter<Self::Item> where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut v = collect_reserved(self);
+        v.sort_by_key(key);
+        SortedIter::new(v)
+    }
+
+    /// Collect all elements into a **Vec**, sort them by the key extracted
+    /// with **key** and return an iterator of the sorted elements, calling
+    /// **key** exactly once per element.
+    ///
+    /// Use this instead of *.sorted_by_key()* when the key is expensive to
+    /// compute; the keys are cached during the sort, like with
+    /// **slice::sort_by_cached_key**. The sort is stable.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = vec!["10", "9", "100", "-1"].into_iter()
+    ///                                      .sorted_by_cached_key(|s| s.parse::<i32>().unwrap());
+    /// itertools::assert_equal(it, vec!["-1", "9", "10", "100"]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_by_cached_key<K, F>(self, key: F) -> SortedIter<Self::Item> where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut v = collect_reserved(self);
+        v.sort_by_cached_key(key);
+        SortedIter::new(v)
+    }
+
+    /// Collect all elements into a **Vec**, sort them with the unstable sort
+    /// and return an iterator of the sorted elements.
+    ///
+    /// The unstable sort does not allocate an auxiliary buffer and is usually
+    /// faster than *.sorted()*, but equal elements may be
+    /// reordered. See [*.sorted_unstable_by_key()*](#method.sorted_unstable_by_key)
+    /// for an example of that.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = vec![3, 1, 4, 1, 5].into_iter().sorted_unstable();
+    /// itertools::assert_equal(it, vec![1, 1, 3, 4, 5]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_unstable(self) -> SortedIter<Self::Item> where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        let mut v = collect_reserved(self);
+        v.sort_unstable();
+        SortedIter::new(v)
+    }
+
+    /// Collect all elements into a **Vec**, sort them with the unstable sort
+    /// using the comparison function **cmp** and return an iterator of the
+    /// sorted elements.
+    ///
+    /// Equal elements may be reordered.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let it = vec![3, 1, 4, 1, 5].into_iter().sorted_unstable_by(|a, b| b.cmp(a));
+    /// itertools::assert_equal(it, vec![5, 4, 3, 1, 1]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_unstable_by<F>(self, cmp: F) -> SortedIter<Self::Item> where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
+    {
+        let mut v = collect_reserved(self);
+        v.sort_unstable_by(cmp);
+        SortedIter::new(v)
+    }
+
+    /// Collect all elements into a **Vec**, sort them with the unstable sort
+    /// by the key extracted with **key** and return an iterator of the
+    /// sorted elements.
+    ///
+    /// Elements with equal keys may end up in any order relative to each
+    /// other, not necessarily the order they had in the input.
+    ///
+    /// Iterator element type is **Self::Item**.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let words = vec!["bb", "a", "cc", "d"];
+    /// let sorted: Vec<_> = words.into_iter().sorted_unstable_by_key(|s| s.len()).collect();
+    ///
+    /// // the keys are in order, but "a" and "d" (or "bb" and "cc") may be swapped
+    /// assert_eq!(sorted.iter().map(|s| s.len()).collect::<Vec<_>>(), vec![1, 1, 2, 2]);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn sorted_unstable_by_key<K, F>(self, key: F) -> SortedIter<Self::Item> where
+        Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
+    {
+        let mut v = collect_reserved(self);
+        v.sort_unstable_by_key(key);
+        SortedIter::new(v)
+    }
+
+    /// Consume the iterator and return an iterator over its last **n**
+    /// elements, in order.
+    ///
+    /// Only the last **n** elements are kept while consuming, in a ring
+    /// buffer of at most **n** elements. If the iterator has fewer than
+    /// **n** elements, all of them are returned.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert!(itertools::equal((0..100).take_last_n(3), vec![97, 98, 99]));
+    /// assert!(itertools::equal((0..2).take_last_n(3), vec![0, 1]));
+    /// assert_eq!((0..2).take_last_n(0).next(), None);
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn take_last_n(self, n: usize) -> vec::IntoIter<Self::Item> where
+        Self: Sized,
+    {
+        let mut buf = Vec::new();
+        // the position of the oldest element, once the buffer is full
+        let mut start = 0;
+        for elt in self {
+            if buf.len() < n {
+                buf.push(elt);
+            } else if n > 0 {
+                buf[start] = elt;
+                start += 1;
+                if start == n {
+                    start = 0;
+                }
+            }
+        }
+        buf.rotate_left(start);
+        buf.into_iter()
+    }
+
+    /// **.try_collect()** is more convenient way of writing
+    /// **.collect::\<Result\<_, _\>\>()**.
+    ///
+    /// Collect the **Ok** values of an iterator of **Result**s into **U**,
+    /// stopping at the first **Err**, which is returned instead.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let lines = vec!["1", "2", "3"];
+    /// let numbers = lines.iter().map(|s| s.parse::<i32>()).try_collect::<Vec<_>>();
+    /// assert_eq!(numbers, Ok(vec![1, 2, 3]));
+    /// ```
+    fn try_collect<U>(self) -> Result<U, <Self::Item as misc::IntoResult>::Err> where
+        Self: Sized,
+        Self::Item: misc::IntoResult,
+        U: iter::FromIterator<<Self::Item as misc::IntoResult>::Ok>,
+    {
+        self.map(misc::IntoResult::into_result).collect()
+    }
+
+    /// Apply **processor** to an iterator over the **Ok** values of this
+    /// iterator of **Result**s, stopping at the first **Err**.
+    ///
+    /// Return **Ok** with the result of **processor**, or the first error if
+    /// one was encountered.
+    ///
+    /// See [*process_results()*](fn.process_results.html) for more
+    /// information.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let max = ["1", "5", "3"].iter().map(|s| s.parse::<i32>())
+    ///                          .process_results(|it| it.max());
+    /// assert_eq!(max, Ok(Some(5)));
+    /// ```
+    fn process_results<F, T, E, R>(self, processor: F) -> Result<R, E> where
+        Self: Sized + Iterator<Item=Result<T, E>>,
+        F: FnOnce(ProcessResults<Self, E>) -> R,
+    {
+        process_results(self, processor)
+    }
+
+    /// Split an iterator of **Result**s into a collection of the **Ok** values
+    /// and a collection of the **Err** values, in one pass.
+    ///
+    /// The collections can be of different types, and each keeps the
+    /// relative order of its values.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let results = vec![Ok(1), Err("a"), Ok(2), Err("b")];
+    /// let (oks, errs): (Vec<i32>, String) = results.into_iter().partition_result();
+    /// assert_eq!(oks, vec![1, 2]);
+    /// assert_eq!(errs, "ab");
+    /// ```
+    fn partition_result<A, B, T, E>(self) -> (A, B) where
+        Self: Sized + Iterator<Item=Result<T, E>>,
+        A: Default + Extend<T>,
+        B: Default + Extend<E>,
+    {
+        let mut oks = A::default();
+        let mut errs = B::default();
+        for elt in self {
+            match elt {
+                Ok(t) => oks.extend(Some(t)),
+                Err(e) => errs.extend(Some(e)),
+            }
+        }
+        (oks, errs)
+    }
+
+    /// Assign to each reference in **self** from the **from** iterator,
+    /// stopping at the shortest of the two iterators.
+    ///
+    /// The **from** iterator is queried for its next element before the **self**
+    /// iterator, and if either is exhausted the method is done.
+    ///
+    /// Return the number of elements written.
+    ///
+    /// ## Example
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut xs = [0; 4];
+    /// xs.iter_mut().set_from(1..);
+    /// assert_eq!(xs, [1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    fn set_from<'a, A: 'a, J>(&mut self, from: J) -> usize where
+        Self: Iterator<Item=&'a mut A>,
+        J: IntoIterator<Item=A>,
+    {
+        let mut count = 0;
+        for elt in from {
+            match self.next() {
+                None => break,
+                Some(ptr) => *ptr = elt
+            }
+            count += 1;
+        }
+        count
+    }
+
+    /// Combine all iterator elements into one String, seperated by **sep**.
+    ///
+    /// Use the **Display** implementation of each element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!(["a", "b", "c"].iter().join(", "), "a, b, c");
+    /// assert_eq!([1, 2, 3].iter().join(", "), "1, 2, 3");
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn join(&mut self, sep: &str) -> String where
+        Self::Item: fmt::Display,
     {
-        Coalesce::new(self, f)
+        match self.next() {
+            None => String::new(),
+            Some(first_elt) => {
+                // estimate lower bound of capacity needed
+                let (lower, _) = self.size_hint();
+                let mut result = String::with_capacity(sep.len() * lower);
+                write!(&mut result, "{}", first_elt).unwrap();
+                for elt in self {
+                    result.push_str(sep);
+                    write!(&mut result, "{}", elt).unwrap();
+                }
+                result
+            }
+        }
     }
 
-    /// Remove duplicates from sections of consecutive identical elements.
-    /// If the iterator is sorted, all elements will be unique.
+    /// Write all iterator elements into **writer**, separated by **sep**.
     ///
-    /// Iterator element type is **Self::Item**.
+    /// Like *.join()*, but the **Display** output of each element is
+    /// streamed straight into the **fmt::Write** sink, without building a
+    /// **String**. If writing fails, the error is returned right away and
+    /// no further elements are consumed.
     ///
-    /// This iterator is *fused*.
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let mut s = String::from("values: ");
+    /// (1..4).write_join(&mut s, ", ").unwrap();
+    /// assert_eq!(s, "values: 1, 2, 3");
+    /// ```
+    fn write_join<W>(&mut self, writer: &mut W, sep: &str) -> fmt::Result where
+        W: fmt::Write,
+        Self::Item: fmt::Display,
+    {
+        if let Some(first_elt) = self.next() {
+            try!(write!(writer, "{}", first_elt));
+            for elt in self {
+                try!(writer.write_str(sep));
+                try!(write!(writer, "{}", elt));
+            }
+        }
+        Ok(())
+    }
+
+    /// Write all iterator elements into the **io::Write** sink **writer**,
+    /// separated by **sep**.
+    ///
+    /// Like *.write_join()*, but for byte sinks like files and sockets.
+    /// If writing fails, the error is returned right away and no further
+    /// elements are consumed.
     ///
     /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let data = vec![1., 1., 2., 3., 3., 2., 2.];
-    /// assert!(itertools::equal(data.into_iter().dedup(),
-    ///                          vec![1., 2., 3., 2.]));
+    /// let mut out = Vec::new();
+    /// ["a", "b", "c"].iter().write_join_io(&mut out, "/").unwrap();
+    /// assert_eq!(out, b"a/b/c");
     /// ```
-    fn dedup(self) -> CoalesceFn<Self> where
-        Self: Sized,
-        Self::Item: PartialEq,
+    #[cfg(feature = "use_std")]
+    fn write_join_io<W>(&mut self, writer: &mut W, sep: &str) -> io::Result<()> where
+        W: io::Write,
+        Self::Item: fmt::Display,
     {
-        fn eq<T: PartialEq>(x: T, y: T) -> Result<T, (T, T)>
-        {
-            if x == y { Ok(x) } else { Err((x, y)) }
+        if let Some(first_elt) = self.next() {
+            try!(write!(writer, "{}", first_elt));
+            for elt in self {
+                try!(writer.write_all(sep.as_bytes()));
+                try!(write!(writer, "{}", elt));
+            }
         }
-        Coalesce::new(self, eq)
+        Ok(())
     }
 
+    /// Format all iterator elements, separated by **sep**.
+    ///
+    /// All elements are formatted with their **Display** implementation,
+    /// and the formatter's options (like precision) are passed on to each
+    /// element, with **sep** inserted between each element.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = [1.1f64, 2.71828, -3.];
+    /// assert_eq!(format!("{:.2}", data.iter().format(", ")), "1.10, 2.72, -3.00");
+    /// ```
+    fn format(self, sep: &str) -> Format<'_, Self> where
+        Self: Sized,
+    {
+        format::new_format(self, sep)
+    }
 
-    /// Return an iterator adaptor that joins together adjacent slices if possible.
+    /// Format all iterator elements, separated by **sep**.
     ///
-    /// Only implemented for iterators with slice or string slice elements.
-    /// Only slices that are contiguous together can be joined.
+    /// This is a customizable version of *.format()*.
+    ///
+    /// The supplied closure **format** is called once per iterator element,
+    /// with two arguments: the element and a callback that takes a
+    /// **&Display** value, i.e. any reference to type that implements
+    /// **Display**.
+    ///
+    /// Using **&format_args!(...)** is the most versatile way to apply
+    /// custom element formatting. The callback can be called multiple times
+    /// if needed.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
     ///
     /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let text = String::from("let there be text");
-    /// let excerpts = vec![&text[0..4], &text[4..9], &text[10..12], &text[12..]];
+    /// let data = [1.1f64, 2.71828, -3.];
+    /// let data_formatter = data.iter().format_with(", ", |elt, f| f(&format_args!("{:.2}", elt)));
+    /// assert_eq!(format!("{}", data_formatter), "1.10, 2.72, -3.00");
+    /// ```
+    fn format_with<F>(self, sep: &str, format: F) -> FormatWith<'_, Self, F> where
+        Self: Sized,
+        F: FnMut(Self::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result,
+    {
+        format::new_format_with(self, sep, format)
+    }
+
+    /// Format all iterator elements, separated by **sep**, passing the
+    /// index of each element to the closure.
+    ///
+    /// Like *.format_with()*, but **format** also receives the position of
+    /// the element, counting from zero, so the output can depend on where
+    /// the element is in the sequence.
+    ///
+    /// **Panics** if the formatter helper is formatted more than once.
+    ///
+    /// ## Example
     ///
-    /// assert!(itertools::equal(excerpts.into_iter().mend_slices(),
-    ///                          vec!["let there", "be text"]));
     /// ```
-    fn mend_slices(self) -> CoalesceFn<Self> where
+    /// use itertools::Itertools;
+    ///
+    /// let letters = ['a', 'b', 'c'];
+    /// let numbered = letters.iter().format_with_index(", ", |i, elt, f| {
+    ///     f(&format_args!("{}:{}", i, elt))
+    /// });
+    /// assert_eq!(format!("{}", numbered), "0:a, 1:b, 2:c");
+    /// ```
+    fn format_with_index<F>(self, sep: &str, format: F) -> FormatWithIndex<'_, Self, F> where
         Self: Sized,
-        Self::Item: misc::MendSlice
+        F: FnMut(usize, Self::Item, &mut dyn FnMut(&dyn fmt::Display) -> fmt::Result) -> fmt::Result,
     {
-        fn mend<T: misc::MendSlice>(x: T, y: T) -> Result<T, (T, T)>
-        {
-            match misc::MendSlice::mend(x, y) {
-                Some(z) => Ok(z),
-                None => Err((x, y)),
+        format::new_format_with_index(self, sep, format)
+    }
+
+    /// Fold **Result** values from an iterator.
+    ///
+    /// Only **Ok** values are folded. If no error is encountered, the folded
+    /// value is returned inside **Ok**. Otherwise, the operation terminates
+    /// and returns the first **Err** value it encounters. No iterator elements are
+    /// consumed after the first error.
+    ///
+    /// The first accumulator value is the **start** parameter.
+    /// Each iteration passes the accumulator value and the next value inside **Ok**
+    /// to the fold function **f** and its return value becomes the new accumulator value.
+    ///
+    /// For example the sequence *Ok(1), Ok(2), Ok(3)* will result in a
+    /// computation like this:
+    ///
+    /// ```ignore
+    /// let mut accum = start;
+    /// accum = f(accum, 1);
+    /// accum = f(accum, 2);
+    /// accum = f(accum, 3);
+    /// ```
+    ///
+    /// With a **start** value of 0 and an addition as folding function,
+    /// this effetively results in *((0 + 1) + 2) + 3*
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use std::ops::Add;
+    /// use itertools::Itertools;
+    ///
+    /// let values = [1, 2, -2, -1, 2, 1];
+    /// assert_eq!(
+    ///     values.iter()
+    ///         .map(Ok::<_, ()>)
+    ///         .fold_results(0, Add::add),
+    ///     Ok(3)
+    /// );
+    /// assert!(
+    ///     values.iter()
+    ///         .map(|&x| if x >= 0 { Ok(x) } else { Err("Negative number") })
+    ///         .fold_results(0, Add::add)
+    ///         .is_err()
+    /// );
+    /// ```
+    fn fold_results<A, E, B, F>(&mut self, mut start: B, mut f: F) -> Result<B, E> where
+        Self: Iterator<Item=Result<A, E>>,
+        F: FnMut(B, A) -> B,
+    {
+        for elt in self {
+            match elt {
+                Ok(v) => start = f(start, v),
+                Err(u) => return Err(u),
             }
         }
-        Coalesce::new(self, mend)
+        Ok(start)
     }
 
-    /// Return an iterator adaptor that borrows from a **Clone**-able iterator
-    /// to only pick off elements while the predicate **f** returns **true**.
+    /// Accumulator of the elements in the iterator.
     ///
-    /// It uses the **Clone** trait to restore the original iterator so that the last
-    /// and rejected element is still available when **TakeWhileRef** is done.
+    /// Like *.fold()*, without a base case. If the iterator is
+    /// empty, return **None**. With just one element, return it.
+    /// Otherwise elements are accumulated in sequence using the closure **f**.
     ///
     /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let mut alphanumerics = "abcdef012345".chars();
+    /// assert_eq!((0..10).fold1(|x, y| x + y).unwrap_or(0), 45);
+    /// assert_eq!((0..0).fold1(|x, y| x * y), None);
+    /// ```
+    fn fold1<F>(&mut self, mut f: F) -> Option<Self::Item> where
+        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    {
+        match self.next() {
+            None => None,
+            Some(mut x) => {
+                for y in self {
+                    x = f(x, y);
+                }
+                Some(x)
+            }
+        }
+    }
+
+    /// Fold the elements of the iterator, with **f** deciding after each
+    /// element whether to go on.
     ///
-    /// let alphas = alphanumerics.take_while_ref(|c| c.is_alphabetic())
-    ///                           .collect::<String>();
-    /// assert_eq!(alphas, "abcdef");
-    /// assert_eq!(alphanumerics.next(), Some('0'));
+    /// **f** takes the accumulator by value and returns it wrapped in
+    /// **FoldWhile::Continue** to keep folding, or in **FoldWhile::Done** to
+    /// stop. The result is the last value returned by **f**, or
+    /// **Continue(init)** for an empty iterator.
+    ///
+    /// The fold runs through *.try_fold()*, so no elements are pulled from
+    /// the iterator after **f** returns **Done**, and the rest of the
+    /// iterator can still be used.
+    ///
+    /// ## Example
     ///
     /// ```
-    fn take_while_ref<'a, F>(&'a mut self, f: F) -> TakeWhileRef<'a, Self, F> where
-        Self: Clone,
-        F: FnMut(&Self::Item) -> bool,
+    /// use itertools::Itertools;
+    /// use itertools::FoldWhile::{Continue, Done};
+    ///
+    /// // sum until the total would go over 10
+    /// let r = (1..10).fold_while(0, |acc, x| {
+    ///     if acc + x > 10 { Done(acc) } else { Continue(acc + x) }
+    /// });
+    /// assert_eq!(r, Done(10));
+    /// assert!(r.is_done());
+    ///
+    /// let mut it = 1..4;
+    /// let r = it.fold_while(0, |acc, x| if x == 2 { Done(acc) } else { Continue(acc + x) });
+    /// assert_eq!(r, Done(1));
+    /// assert_eq!(it.next(), Some(3));
+    /// ```
+    fn fold_while<B, F>(&mut self, init: B, mut f: F) -> FoldWhile<B> where
+        Self: Sized,
+        F: FnMut(B, Self::Item) -> FoldWhile<B>,
     {
-        TakeWhileRef::new(self, f)
+        let result = self.try_fold(init, |acc, elt| {
+            match f(acc, elt) {
+                FoldWhile::Continue(acc) => Ok(acc),
+                FoldWhile::Done(acc) => Err(acc),
+            }
+        });
+        match result {
+            Ok(acc) => FoldWhile::Continue(acc),
+            Err(acc) => FoldWhile::Done(acc),
+        }
     }
 
-    /// Return an iterator adaptor that iterates over the combinations of
-    /// the elements from an iterator.
+    /// Sum the elements of an iterator of primitive integers, or return
+    /// **None** if the sum overflows.
     ///
-    /// Iterator element type is **(Self::Item, Self::Item)**.
+    /// The iterator is consumed up to and including the element that makes
+    /// the sum overflow. An empty iterator sums to zero.
+    ///
+    /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let it = (1..5).combinate_pairs();
-    /// assert!(itertools::equal(it, vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)]));
+    /// assert_eq!([200u8, 55].iter().checked_sum::<u8>(), Some(255));
+    /// assert_eq!([200u8, 56].iter().checked_sum::<u8>(), None);
     /// ```
-    fn combinations(self) -> Combinations<Self> where
-        Self: Sized + Clone, Self::Item: Clone
+    fn checked_sum<S>(self) -> Option<S> where
+        Self: Sized,
+        S: misc::CheckedSummable<Self::Item>,
     {
-        Combinations::new(self)
+        S::checked_sum(self)
     }
 
-    /// Like regular *.map()*, specialized to using a simple function pointer instead,
-    /// so that the resulting **Map** iterator value can be cloned.
+    /// Multiply the elements of an iterator of primitive integers, or return
+    /// **None** if the product overflows.
     ///
-    /// Iterator element type is **B**.
-    fn map_fn<B>(self, f: fn(Self::Item) -> B) -> MapFn<Self, B> where
-        Self: Sized
+    /// The iterator is consumed up to and including the element that makes
+    /// the product overflow. The product of an empty iterator is one.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((1..6).checked_product::<i32>(), Some(120));
+    /// assert_eq!((1..20).checked_product::<i32>(), None);
+    /// ```
+    fn checked_product<S>(self) -> Option<S> where
+        Self: Sized,
+        S: misc::CheckedSummable<Self::Item>,
     {
-        self.map(f)
+        S::checked_product(self)
     }
 
-    /// **Deprecated:** Use *.map_fn()* instead.
-    fn fn_map<B>(self, map: fn(Self::Item) -> B) -> FnMap<B, Self> where
-        Self: Sized
+    /// Sum the elements of the iterator, or return **None** if it is empty.
+    ///
+    /// Like *.sum()*, but without an identity: the sum starts from the
+    /// first element, so an empty iterator can be told apart from one
+    /// that sums to zero.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((1..4).sum1::<i32>(), Some(6));
+    /// assert_eq!((-1..2).sum1::<i32>(), Some(0));
+    /// assert_eq!((0..0).sum1::<i32>(), None);
+    /// ```
+    fn sum1<S>(mut self) -> Option<S> where
+        Self: Sized,
+        S: iter::Sum<Self::Item>,
     {
-        FnMap::new(self, map)
+        self.next()
+            .map(|first| iter::once(first).chain(self).sum())
     }
 
+    /// Multiply the elements of the iterator, or return **None** if it is
+    /// empty.
+    ///
+    /// Like *.product()*, but without an identity: the product starts from
+    /// the first element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((1..5).product1::<i32>(), Some(24));
+    /// assert_eq!((0..0).product1::<i32>(), None);
+    /// ```
+    fn product1<P>(mut self) -> Option<P> where
+        Self: Sized,
+        P: iter::Product<Self::Item>,
+    {
+        self.next()
+            .map(|first| iter::once(first).chain(self).product())
+    }
 
-    // non-adaptor methods
+    /// If the iterator yields exactly one element, return **Ok(elt)**.
+    ///
+    /// Otherwise return an **Err** value: an **ExactlyOneError** that is an
+    /// iterator over all the elements of the original iterator, including the
+    /// ones already pulled. Its **Display** implementation tells if there
+    /// were zero or more than one elements.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((0..10).filter(|&x| x == 2).exactly_one().unwrap(), 2);
+    /// assert!((0..10).filter(|&x| x > 1 && x < 4).exactly_one().unwrap_err().eq(2..4));
+    /// assert!((0..10).filter(|&x| x > 1 && x < 5).exactly_one().unwrap_err().eq(2..5));
+    /// assert!((0..10).filter(|&_| false).exactly_one().unwrap_err().eq(0..0));
+    /// ```
+    fn exactly_one(mut self) -> Result<Self::Item, ExactlyOneError<Self>> where
+        Self: Sized,
+    {
+        match self.next() {
+            Some(first) => {
+                match self.next() {
+                    Some(second) => {
+                        Err(ExactlyOneError::new((Some(first), Some(second)), self))
+                    }
+                    None => Ok(first),
+                }
+            }
+            None => Err(ExactlyOneError::new((None, None), self)),
+        }
+    }
 
-    /// Find the position and value of the first element satisfying a predicate.
-    fn find_position<P>(&mut self, mut pred: P) -> Option<(usize, Self::Item)> where
-        P: FnMut(&Self::Item) -> bool,
+    /// If the iterator yields no elements, return **Ok(None)**.
+    /// If it yields exactly one element, return **Ok(Some(elt))**.
+    ///
+    /// Otherwise return an **Err** value: an **ExactlyOneError** that is an
+    /// iterator over all the elements of the original iterator, including the
+    /// first two that were pulled. At most two elements are pulled before
+    /// deciding.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// assert_eq!((0..10).filter(|&x| x == 2).at_most_one().unwrap(), Some(2));
+    /// assert!((0..10).filter(|&x| x > 1 && x < 4).at_most_one().unwrap_err().eq(2..4));
+    /// assert_eq!((0..10).filter(|&_| false).at_most_one().unwrap(), None);
+    /// ```
+    fn at_most_one(mut self) -> Result<Option<Self::Item>, ExactlyOneError<Self>> where
+        Self: Sized,
     {
-        let mut index = 0usize;
-        for elt in self {
-            if pred(&elt) {
-                return Some((index, elt))
+        match self.next() {
+            Some(first) => {
+                match self.next() {
+                    Some(second) => {
+                        Err(ExactlyOneError::new_at_most_one((Some(first), Some(second)), self))
+                    }
+                    None => Ok(Some(first)),
+                }
             }
-            index += 1;
+            None => Ok(None),
         }
-        None
     }
 
-    /// Consume the first **n** elements of the iterator eagerly.
+    /// Check whether all elements are unique (non equal).
     ///
-    /// Return actual number of elements consumed,
-    /// until done or reaching the end.
-    fn dropn(&mut self, mut n: usize) -> usize
+    /// Empty iterators are considered to have unique elements. The iterator is
+    /// consumed up to and including the first repeated element.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    ///
+    /// let data = vec![1, 2, 3, 4, 1, 5];
+    /// assert!(!data.iter().all_unique());
+    /// assert!(data[0..4].iter().all_unique());
+    /// assert!(data[1..6].iter().all_unique());
+    ///
+    /// let data : Option<usize> = None;
+    /// assert!(data.into_iter().all_unique());
+    /// ```
+    #[cfg(feature = "use_std")]
+    fn all_unique(&mut self) -> bool where
+        Self::Item: Eq + Hash,
     {
-        let start = n;
-        while n > 0 {
-            match self.next() {
-                Some(..) => n -= 1,
-                None => break
+        let mut used = HashSet::new();
+        for elt in self {
+            if !used.insert(elt) {
+                return false
             }
         }
-        start - n
+        true
     }
 
-    /// Consume the first **n** elements from the iterator eagerly,
-    /// and return the same iterator again.
+    /// Return the minimum and maximum elements in the iterator.
     ///
-    /// It works similarly to **.skip(n)** except it is eager and
-    /// preserves the iterator type.
-    fn dropping(mut self, n: usize) -> Self where
+    /// The return type **MinMaxResult** is an enum of three variants:
+    ///
+    /// - **NoElements** if the iterator is empty.
+    /// - **OneElement(x)** if the iterator has exactly one element.
+    /// - **MinMax(x, y)** is returned otherwise, where **x <= y**. Two
+    ///   values are equal if and only if there is more than one
+    ///   element in the iterator and all elements are equal.
+    ///
+    /// On an iterator of length **n**, *.minmax()* does **1.5 * n**
+    /// comparisons, and so is faster than calling *.min()* and *.max()*
+    /// separately which does **2 * n** comparisons.
+    ///
+    /// If several elements are equally minimum, the first element is returned.
+    /// If several elements are equally maximum, the last element is returned.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::{NoElements, OneElement, MinMax};
+    ///
+    /// let a: [i32; 0] = [];
+    /// assert_eq!(a.iter().minmax(), NoElements);
+    ///
+    /// let a = [1];
+    /// assert_eq!(a.iter().minmax(), OneElement(&1));
+    ///
+    /// let a = [1, 2, 3, 4, 5];
+    /// assert_eq!(a.iter().minmax(), MinMax(&1, &5));
+    ///
+    /// let a = [1, 1, 1, 1];
+    /// assert_eq!(a.iter().minmax(), MinMax(&1, &1));
+    /// ```
+    fn minmax(self) -> MinMaxResult<Self::Item> where
         Self: Sized,
+        Self::Item: Ord,
     {
-        self.dropn(n);
-        self
+        minmax::minmax_impl(self, |_| (), |x, y, _, _| x < y)
     }
 
-    /// Consume the last **n** elements from the iterator eagerly,
-    /// and return the same iterator again.
+    /// Return the minimum and maximum element of an iterator, as determined
+    /// by the specified comparison function.
     ///
-    /// This is only possible on double ended iterators. **n** may be
-    /// larger than the number of elements.
+    /// The return value is a variant of **MinMaxResult** like for
+    /// *.minmax()*, and it also does **1.5 * n** comparisons.
     ///
-    /// Note: This method is eager, dropping the back elements immediately and
-    /// preserves the iterator type.
+    /// If several elements are equally minimum, the first element is returned.
+    /// If several elements are equally maximum, the last element is returned.
     ///
     /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::MinMax;
     ///
-    /// let init = vec![0, 3, 6, 9].into_iter().dropping_back(1);
-    /// assert!(itertools::equal(init, vec![0, 3, 6]));
+    /// let words = ["abc", "de", "f", "gh", "ijk"];
+    /// assert_eq!(words.iter().minmax_by(|a, b| a.len().cmp(&b.len())),
+    ///            MinMax(&"f", &"ijk"));
     /// ```
-    fn dropping_back(mut self, n: usize) -> Self where
+    fn minmax_by<F>(self, mut compare: F) -> MinMaxResult<Self::Item> where
         Self: Sized,
-        Self: DoubleEndedIterator,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        self.by_ref().rev().dropn(n);
-        self
+        minmax::minmax_impl(self, |_| (), |x, y, _, _| compare(x, y) == Ordering::Less)
     }
 
-    /// Run the closure **f** eagerly on each element of the iterator.
+    /// Return the minimum and maximum elements of the iterator together
+    /// with their indices, in one pass.
     ///
-    /// Consumes the iterator until its end.
+    /// The return value is a variant of **MinMaxResult** of
+    /// **(index, element)** pairs, with the same comparison count and tie
+    /// breaking as *.minmax()*: the first minimum and the last maximum.
     ///
     /// ## Example
     ///
     /// ```
-    /// use std::sync::mpsc::channel;
     /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::MinMax;
     ///
-    /// let (tx, rx) = channel();
+    /// let a = [3, 1, 4, 1, 5, 9, 2, 9];
+    /// assert_eq!(a.iter().minmax_full(), MinMax((1, &1), (7, &9)));
+    /// ```
+    fn minmax_full(self) -> MinMaxResult<(usize, Self::Item)> where
+        Self: Sized,
+        Self::Item: Ord,
+    {
+        minmax::minmax_impl(self.enumerate(), |_| (), |x, y, _, _| x.1 < y.1)
+    }
+
+    /// Return the minimum and maximum elements of the iterator together
+    /// with their indices, as determined by the specified comparison
+    /// function.
     ///
-    /// // use .foreach() to apply a function to each value -- sending it
-    /// (0..5).map(|x| x * 2 + 1).foreach(|x| { tx.send(x).unwrap(); } );
+    /// See [*.minmax_full()*](#method.minmax_full) for more information.
     ///
-    /// drop(tx);
+    /// ## Example
     ///
-    /// assert!(itertools::equal(rx.iter(), vec![1, 3, 5, 7, 9]));
     /// ```
-    fn foreach<F>(&mut self, mut f: F) where
-        F: FnMut(Self::Item),
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::MinMax;
+    ///
+    /// let a = [-3i32, 1, 4, -1, 3];
+    /// assert_eq!(a.iter().minmax_full_by(|x, y| x.abs().cmp(&y.abs())),
+    ///            MinMax((1, &1), (2, &4)));
+    /// ```
+    fn minmax_full_by<F>(self, mut compare: F) -> MinMaxResult<(usize, Self::Item)> where
+        Self: Sized,
+        F: FnMut(&Self::Item, &Self::Item) -> Ordering,
     {
-        for elt in self { f(elt) }
+        minmax::minmax_impl(self.enumerate(), |_| (),
+                            |x, y, _, _| compare(&x.1, &y.1) == Ordering::Less)
     }
 
-    /// **.collect_vec()** is simply a type specialization of **.collect()**,
-    /// for convenience.
-    fn collect_vec(self) -> Vec<Self::Item> where
+    /// Return the minimum and maximum elements of the iterator together
+    /// with their indices, as determined by the specified key function.
+    ///
+    /// The key function is called exactly once per element. See
+    /// [*.minmax_full()*](#method.minmax_full) for more information.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::Itertools;
+    /// use itertools::MinMaxResult::MinMax;
+    ///
+    /// let words = ["apple", "fig", "kiwi", "banana", "pea"];
+    /// assert_eq!(words.iter().minmax_full_by_key(|w| w.len()),
+    ///            MinMax((1, &"fig"), (3, &"banana")));
+    /// ```
+    fn minmax_full_by_key<K, F>(self, mut key: F) -> MinMaxResult<(usize, Self::Item)> where
         Self: Sized,
+        K: Ord,
+        F: FnMut(&Self::Item) -> K,
     {
-        self.collect()
+        minmax::minmax_impl(self.enumerate(), |x| key(&x.1), |_, _, xk, yk| xk < yk)
     }
 
-    /// Assign to each reference in **self** from the **from** iterator,
-    /// stopping at the shortest of the two iterators.
-    ///
-    /// The **from** iterator is queried for its next element before the **self**
-    /// iterator, and if either is exhausted the method is done.
+    /// Check whether all elements compare equal.
     ///
-    /// Return the number of elements written.
+    /// Empty iterators are considered to have equal elements. The iterator is
+    /// consumed up to and including the first element that differs.
     ///
     /// ## Example
+    ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// let mut xs = [0; 4];
-    /// xs.iter_mut().set_from(1..);
-    /// assert_eq!(xs, [1, 2, 3, 4]);
+    /// let data = vec![1, 1, 1, 2, 2, 3, 3, 3, 4, 5, 5];
+    /// assert!(!data.iter().all_equal());
+    /// assert!(data[0..3].iter().all_equal());
+    /// assert!(data[3..5].iter().all_equal());
+    /// assert!(data[5..8].iter().all_equal());
+    ///
+    /// let data : Option<usize> = None;
+    /// assert!(data.into_iter().all_equal());
     /// ```
-    #[inline]
-    fn set_from<'a, A: 'a, J>(&mut self, from: J) -> usize where
-        Self: Iterator<Item=&'a mut A>,
-        J: IntoIterator<Item=A>,
+    fn all_equal(&mut self) -> bool where
+        Self::Item: PartialEq,
     {
-        let mut count = 0;
-        for elt in from {
-            match self.next() {
-                None => break,
-                Some(ptr) => *ptr = elt
+        let first = match self.next() {
+            None => return true,
+            Some(a) => a,
+        };
+        for elt in self {
+            if elt != first {
+                return false
             }
-            count += 1;
         }
-        count
+        true
     }
 
-    /// Combine all iterator elements into one String, seperated by **sep**.
+    /// If all elements compare equal, return the first of them in **Ok**.
     ///
-    /// Use the **Display** implementation of each element.
+    /// Return **Err(None)** if the iterator is empty, or **Err(Some((a, b)))**
+    /// with the first element **a** and the first element **b** that differs
+    /// from it. The iterator is consumed up to and including **b**.
     ///
     /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// assert_eq!(["a", "b", "c"].iter().join(", "), "a, b, c");
-    /// assert_eq!([1, 2, 3].iter().join(", "), "1, 2, 3");
+    /// let data = vec![1, 1, 1, 2, 2, 3, 3, 3, 4, 5, 5];
+    /// assert_eq!(data.iter().all_equal_value(), Err(Some((&1, &2))));
+    /// assert_eq!(data[0..3].iter().all_equal_value(), Ok(&1));
+    /// assert_eq!(data[3..5].iter().all_equal_value(), Ok(&2));
+    ///
+    /// let data : Option<usize> = None;
+    /// assert_eq!(data.into_iter().all_equal_value(), Err(None));
     /// ```
-    fn join(&mut self, sep: &str) -> String where
-        Self::Item: std::fmt::Display,
+    fn all_equal_value(&mut self) -> Result<Self::Item, AllEqualValueError<Self::Item>> where
+        Self::Item: PartialEq,
     {
-        match self.next() {
-            None => String::new(),
-            Some(first_elt) => {
-                // estimate lower bound of capacity needed
-                let (lower, _) = self.size_hint();
-                let mut result = String::with_capacity(sep.len() * lower);
-                write!(&mut result, "{}", first_elt).unwrap();
-                for elt in self {
-                    result.push_str(sep);
-                    write!(&mut result, "{}", elt).unwrap();
-                }
-                result
+        let first = match self.next() {
+            None => return Err(None),
+            Some(a) => a,
+        };
+        for elt in self {
+            if elt != first {
+                return Err(Some((first, elt)))
             }
         }
+        Ok(first)
     }
 
-    /// Fold **Result** values from an iterator.
+    /// Return **true** if the elements of the iterator are in ascending
+    /// order.
     ///
-    /// Only **Ok** values are folded. If no error is encountered, the folded
-    /// value is returned inside **Ok**. Otherwise, the operation terminates
-    /// and returns the first **Err** value it encounters. No iterator elements are
-    /// consumed after the first error.
+    /// The iterator is consumed up to and including the first element that
+    /// is out of order with its predecessor, or that cannot be compared with
+    /// it (like a floating point NaN). Empty and single element iterators
+    /// are sorted.
     ///
-    /// The first accumulator value is the **start** parameter.
-    /// Each iteration passes the accumulator value and the next value inside **Ok**
-    /// to the fold function **f** and its return value becomes the new accumulator value.
+    /// Unlike **Iterator::is_sorted**, this takes the iterator by reference,
+    /// so the rest of it can still be used after the first element out of
+    /// order.
     ///
-    /// For example the sequence *Ok(1), Ok(2), Ok(3)* will result in a
-    /// computation like this:
+    /// ## Example
     ///
-    /// ```ignore
-    /// let mut accum = start;
-    /// accum = f(accum, 1);
-    /// accum = f(accum, 2);
-    /// accum = f(accum, 3);
     /// ```
+    /// use itertools::Itertools;
     ///
-    /// With a **start** value of 0 and an addition as folding function,
-    /// this effetively results in *((0 + 1) + 2) + 3*
+    /// assert!([1, 2, 2, 9].iter().all_sorted());
+    /// assert!(![1, 3, 2, 4].iter().all_sorted());
+    /// assert!(![0.0, ::std::f64::NAN].iter().all_sorted());
+    /// assert!((0..0).all_sorted());
+    /// ```
+    fn all_sorted(&mut self) -> bool where
+        Self::Item: PartialOrd,
+    {
+        self.all_sorted_by(|a, b| a.partial_cmp(b))
+    }
+
+    /// Return **true** if the elements of the iterator are sorted according
+    /// to the comparison function **cmp**.
+    ///
+    /// **cmp** is called with each pair of adjacent elements and the pair is
+    /// in order if it returns **Some(Less)** or **Some(Equal)**. **None**
+    /// means the elements are incomparable, so the iterator is not sorted.
+    ///
+    /// The iterator is consumed up to and including the first element that
+    /// is out of order with its predecessor.
     ///
     /// ## Example
     ///
     /// ```
-    /// use std::ops::Add;
     /// use itertools::Itertools;
     ///
-    /// let values = [1, 2, -2, -1, 2, 1];
-    /// assert_eq!(
-    ///     values.iter()
-    ///         .map(Ok::<_, ()>)
-    ///         .fold_results(0, Add::add),
-    ///     Ok(3)
-    /// );
-    /// assert!(
-    ///     values.iter()
-    ///         .map(|&x| if x >= 0 { Ok(x) } else { Err("Negative number") })
-    ///         .fold_results(0, Add::add)
-    ///         .is_err()
-    /// );
+    /// let mut it = [5, 3, 3, 4, 0].iter();
+    /// assert!(!it.all_sorted_by(|a, b| Some(b.cmp(a))));
+    /// assert_eq!(it.next(), Some(&0));
     /// ```
-    fn fold_results<A, E, B, F>(&mut self, mut start: B, mut f: F) -> Result<B, E> where
-        Self: Iterator<Item=Result<A, E>>,
-        F: FnMut(B, A) -> B,
+    fn all_sorted_by<F>(&mut self, mut cmp: F) -> bool where
+        F: FnMut(&Self::Item, &Self::Item) -> Option<Ordering>,
     {
+        let mut last = match self.next() {
+            None => return true,
+            Some(elt) => elt,
+        };
         for elt in self {
-            match elt {
-                Ok(v) => start = f(start, v),
-                Err(u) => return Err(u),
+            match cmp(&last, &elt) {
+                Some(Ordering::Less) | Some(Ordering::Equal) => last = elt,
+                Some(Ordering::Greater) | None => return false,
             }
         }
-        Ok(start)
+        true
     }
 
-    /// Accumulator of the elements in the iterator.
+    /// Return **true** if the keys extracted with **key** from the elements
+    /// of the iterator are in ascending order.
     ///
-    /// Like *.fold()*, without a base case. If the iterator is
-    /// empty, return **None**. With just one element, return it.
-    /// Otherwise elements are accumulated in sequence using the closure **f**.
+    /// The iterator is consumed up to and including the first element whose
+    /// key is out of order with, or incomparable to, the key before it.
     ///
     /// ## Example
     ///
     /// ```
     /// use itertools::Itertools;
     ///
-    /// assert_eq!((0..10).fold1(|x, y| x + y).unwrap_or(0), 45);
-    /// assert_eq!((0..0).fold1(|x, y| x * y), None);
+    /// let words = ["a", "to", "be", "for"];
+    /// assert!(words.iter().all_sorted_by_key(|s| s.len()));
+    /// assert!(!words.iter().all_sorted_by_key(|s| s.chars().last()));
     /// ```
-    fn fold1<F>(&mut self, mut f: F) -> Option<Self::Item> where
-        F: FnMut(Self::Item, Self::Item) -> Self::Item,
+    fn all_sorted_by_key<K, F>(&mut self, key: F) -> bool where
+        F: FnMut(Self::Item) -> K,
+        K: PartialOrd,
     {
-        match self.next() {
-            None => None,
-            Some(mut x) => {
-                for y in self {
-                    x = f(x, y);
-                }
-                Some(x)
-            }
-        }
+        self.map(key).all_sorted()
     }
 
     /// Tell if the iterator is empty or not according to its size hint.
@@ -972,6 +3192,48 @@ pub trait Itertools : Iterator {
     }
 }
 
+/// Create an iterator that clones the elements of an iterable of references.
+///
+/// This is *.cloned()* as a free function, taking any **IntoIterator**.
+/// (It is not an **Itertools** method, because one with the same name as
+/// **Iterator::cloned** would make every *.cloned()* call ambiguous.)
+/// The iterator is double ended and has an exact size if the source does.
+///
+/// Iterator element type is **T**.
+///
+/// ## Example
+///
+/// ```
+/// let v: Vec<_> = itertools::cloned(&[1, 2, 3]).rev().collect();
+/// assert_eq!(v, vec![3, 2, 1]);
+/// ```
+pub fn cloned<'a, I, T>(iterable: I) -> iter::Cloned<I::IntoIter> where
+    I: IntoIterator<Item=&'a T>,
+    T: 'a + Clone,
+{
+    iterable.into_iter().cloned()
+}
+
+/// Create an iterator that copies the elements of an iterable of references.
+///
+/// This is *.copied()* as a free function, taking any **IntoIterator**.
+/// The iterator is double ended and has an exact size if the source does.
+///
+/// Iterator element type is **T**.
+///
+/// ## Example
+///
+/// ```
+/// let words = ["a", "b", "c"];
+/// itertools::assert_equal(itertools::copied(&words), vec!["a", "b", "c"]);
+/// ```
+pub fn copied<'a, I, T>(iterable: I) -> iter::Copied<I::IntoIter> where
+    I: IntoIterator<Item=&'a T>,
+    T: 'a + Copy,
+{
+    iterable.into_iter().copied()
+}
+
 /// Return **true** if both iterators produce equal sequences
 /// (elements pairwise equal and sequences of the same length),
 /// **false** otherwise.
@@ -998,6 +3260,51 @@ pub fn equal<I, J>(a: I, b: J) -> bool where
     }
 }
 
+/// Assert that two iterators produce equal sequences, with the same
+/// semantics as *equal(a, b)*.
+///
+/// **Panics** on assertion failure with a message that shows the index of
+/// the first differing pair of elements, or which of the iterators was longer
+/// and by how many elements.
+///
+/// ## Example
+///
+/// ```should_panic
+/// itertools::assert_equal("exceed".split('c'), "excess".split('c'));
+/// ```
+pub fn assert_equal<I, J>(a: I, b: J) where
+    I: IntoIterator,
+    J: IntoIterator,
+    I::Item: fmt::Debug + PartialEq<J::Item>,
+    J::Item: fmt::Debug,
+{
+    let mut ia = a.into_iter();
+    let mut ib = b.into_iter();
+    let mut i = 0;
+    loop {
+        match (ia.next(), ib.next()) {
+            (None, None) => return,
+            (Some(a), Some(b)) => {
+                if a != b {
+                    panic!("assert_equal: elements differ at index {}: left: `{:?}`, right: `{:?}`",
+                           i, a, b);
+                }
+            }
+            (Some(a), None) => {
+                let extra = 1 + ia.count();
+                panic!("assert_equal: left is longer by {} element(s), \
+                        starting at index {} with `{:?}`", extra, i, a);
+            }
+            (None, Some(b)) => {
+                let extra = 1 + ib.count();
+                panic!("assert_equal: right is longer by {} element(s), \
+                        starting at index {} with `{:?}`", extra, i, b);
+            }
+        }
+        i += 1;
+    }
+}
+
 /// Partition a sequence using predicate **pred** so that elements
 /// that map to **true** are placed before elements which map to **false**.
 ///
@@ -1028,7 +3335,7 @@ pub fn partition<'a, T: 'a, I, F>(it: I, mut pred: F) -> usize where
             loop {
                 if let Some(back) = it.next_back() {
                     if pred(&*back) {
-                        std::mem::swap(front, back);
+                        core::mem::swap(front, back);
                         break;
                     }
                 } else {
@@ -1042,5 +3349,16 @@ pub fn partition<'a, T: 'a, I, F>(it: I, mut pred: F) -> usize where
 }
 
 
+/// Collect **iter** into a **Vec**, reserving space for its lower size hint.
+#[cfg(feature = "use_std")]
+fn collect_reserved<I>(iter: I) -> Vec<I::Item> where
+    I: Iterator,
+{
+    let mut v = Vec::with_capacity(iter.size_hint().0);
+    v.extend(iter);
+    v
+}
+
+
 impl<T: ?Sized> Itertools for T where T: Iterator { }
 
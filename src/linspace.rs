@@ -1,5 +1,5 @@
 use super::misc::ToFloat;
-use std::ops::{Add, Sub, Div};
+use core::ops::{Add, Sub, Div};
 
 /// An iterator of a sequence of evenly spaced floats.
 ///
@@ -0,0 +1,167 @@
+use core::iter::{Chain, IntoIterator};
+use core::option;
+
+/// **MinMaxResult** is an enum returned by *.minmax()*.
+///
+/// See [*.minmax()*](trait.Itertools.html#method.minmax) for more detail.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum MinMaxResult<T> {
+    /// Empty iterator
+    NoElements,
+
+    /// Iterator with one element, so the minimum and maximum are the same
+    OneElement(T),
+
+    /// More than one element in the iterator, the first element is not larger
+    /// than the second
+    MinMax(T, T)
+}
+
+impl<T> MinMaxResult<T> {
+    /// **.into_option()** creates an **Option** of type **(T, T)**.
+    ///
+    /// The returned **Option** is **None** if there are no elements. For a
+    /// single element, that element is cloned to be both the minimum
+    /// and the maximum.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::MinMaxResult::{self, NoElements, OneElement, MinMax};
+    ///
+    /// let r: MinMaxResult<i32> = NoElements;
+    /// assert_eq!(r.into_option(), None);
+    ///
+    /// let r = OneElement(1);
+    /// assert_eq!(r.into_option(), Some((1, 1)));
+    ///
+    /// let r = MinMax(1, 2);
+    /// assert_eq!(r.into_option(), Some((1, 2)));
+    /// ```
+    pub fn into_option(self) -> Option<(T, T)> where
+        T: Clone,
+    {
+        match self {
+            MinMaxResult::NoElements => None,
+            MinMaxResult::OneElement(x) => Some((x.clone(), x)),
+            MinMaxResult::MinMax(x, y) => Some((x, y)),
+        }
+    }
+
+    /// Apply **f** to the contained elements, preserving the variant.
+    ///
+    /// ## Example
+    ///
+    /// ```
+    /// use itertools::MinMaxResult::{OneElement, MinMax};
+    ///
+    /// assert_eq!(OneElement(1).map(|x| x * 10), OneElement(10));
+    /// assert_eq!(MinMax(1, 2).map(|x| x.to_string()),
+    ///            MinMax("1".to_string(), "2".to_string()));
+    /// ```
+    pub fn map<U, F>(self, mut f: F) -> MinMaxResult<U> where
+        F: FnMut(T) -> U,
+    {
+        match self {
+            MinMaxResult::NoElements => MinMaxResult::NoElements,
+            MinMaxResult::OneElement(x) => MinMaxResult::OneElement(f(x)),
+            MinMaxResult::MinMax(x, y) => {
+                let x = f(x);
+                MinMaxResult::MinMax(x, f(y))
+            }
+        }
+    }
+}
+
+/// Iterate the zero, one or two elements of the **MinMaxResult**,
+/// minimum first.
+impl<T> IntoIterator for MinMaxResult<T> {
+    type Item = T;
+    type IntoIter = Chain<option::IntoIter<T>, option::IntoIter<T>>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let (a, b) = match self {
+            MinMaxResult::NoElements => (None, None),
+            MinMaxResult::OneElement(x) => (Some(x), None),
+            MinMaxResult::MinMax(x, y) => (Some(x), Some(y)),
+        };
+        a.into_iter().chain(b)
+    }
+}
+
+/// Implementation guts for **minmax**.
+///
+/// **lt** compares two elements by value or by their keys from **key_for**.
+pub fn minmax_impl<I, K, F, L>(mut it: I, mut key_for: F,
+                               mut lt: L) -> MinMaxResult<I::Item> where
+    I: Iterator,
+    F: FnMut(&I::Item) -> K,
+    L: FnMut(&I::Item, &I::Item, &K, &K) -> bool,
+{
+    let (mut min, mut max, mut min_key, mut max_key) = match it.next() {
+        None => return MinMaxResult::NoElements,
+        Some(x) => {
+            match it.next() {
+                None => return MinMaxResult::OneElement(x),
+                Some(y) => {
+                    let xk = key_for(&x);
+                    let yk = key_for(&y);
+                    if !lt(&y, &x, &yk, &xk) {
+                        (x, y, xk, yk)
+                    } else {
+                        (y, x, yk, xk)
+                    }
+                }
+            }
+        }
+    };
+
+    loop {
+        // `first` and `second` are the two next elements we want to look
+        // at.  We first compare `first` and `second` (#1). The smaller one
+        // is then compared to current minimum (#2). The larger one is
+        // compared to current maximum (#3). This is a total of 3
+        // comparisons for 2 elements.  Note that this is less than the
+        // 2 * (n - 1) comparisons needed by the naive approach.
+        let first = match it.next() {
+            None => break,
+            Some(x) => x
+        };
+        let second = match it.next() {
+            None => {
+                let first_key = key_for(&first);
+                if lt(&first, &min, &first_key, &min_key) {
+                    min = first;
+                } else if !lt(&first, &max, &first_key, &max_key) {
+                    max = first;
+                }
+                break;
+            }
+            Some(x) => x
+        };
+        let first_key = key_for(&first);
+        let second_key = key_for(&second);
+        if !lt(&second, &first, &second_key, &first_key) {
+            if lt(&first, &min, &first_key, &min_key) {
+                min = first;
+                min_key = first_key;
+            }
+            if !lt(&second, &max, &second_key, &max_key) {
+                max = second;
+                max_key = second_key;
+            }
+        } else {
+            if lt(&second, &min, &second_key, &min_key) {
+                min = second;
+                min_key = second_key;
+            }
+            if !lt(&first, &max, &first_key, &max_key) {
+                max = first;
+                max_key = first_key;
+            }
+        }
+    }
+
+    MinMaxResult::MinMax(min, max)
+}
@@ -1,15 +1,15 @@
 //! A module of helper traits and iterators that are not intended to be used
 //! directly.
 
-use std::ops::{
+use core::ops::{
     RangeFull,
     Range,
     RangeTo,
     RangeFrom
 };
 
-use std::mem;
-use std::slice;
+use core::mem;
+use core::slice;
 
 /// Apply **IntoIterator** on each element of a tuple.
 pub trait IntoIteratorTuple
@@ -58,7 +58,7 @@ impl_append_tuple!(A, B, C, D, E, F, G, H, I, J, K, L,);
 /// A helper iterator that maps an iterator of tuples like
 /// `((A, B), C)` to an iterator of `(A, B, C)`.
 ///
-/// Used by the `iproduct!()` macro.
+/// Used by the `iproduct!()` macro and *.cons_tuples()*.
 #[derive(Clone)]
 pub struct FlatTuples<I> {
     iter: I,
@@ -101,6 +101,11 @@ impl<X, T, I> DoubleEndedIterator for FlatTuples<I> where
     }
 }
 
+impl<X, T, I> ExactSizeIterator for FlatTuples<I> where
+    I: ExactSizeIterator<Item=(T, X)>,
+    T: AppendTuple<X>,
+{ }
+
 /// **GenericRange** is implemented by Rust's built-in range types, produced
 /// by range syntax like `a..`, `..b` or `c..d`.
 pub trait GenericRange {
@@ -175,3 +180,141 @@ impl<'a> MendSlice for &'a str
         }
     }
 }
+
+/// Implemented for homogeneous tuples of size up to 12,
+/// used for implementing *.tuple_combinations()*.
+pub trait TupleCollect : Sized {
+    /// Element type of the tuple.
+    type Item;
+
+    #[doc(hidden)]
+    /// Number of elements in the tuple.
+    fn num_items() -> usize;
+
+    #[doc(hidden)]
+    /// Build a tuple from the first elements of **iter**, or return **None**
+    /// if it runs out too early.
+    fn collect_from_iter<I>(iter: I) -> Option<Self> where
+        I: IntoIterator<Item=Self::Item>;
+}
+
+macro_rules! impl_tuple_collect {
+    ($N:expr; $T:ty; $($x:ident),*) => (
+        impl<A> TupleCollect for $T {
+            type Item = A;
+
+            fn num_items() -> usize { $N }
+
+            fn collect_from_iter<I>(iter: I) -> Option<Self> where
+                I: IntoIterator<Item=A>,
+            {
+                let mut iter = iter.into_iter();
+                $(
+                    let $x = match iter.next() {
+                        None => return None,
+                        Some(elt) => elt
+                    };
+                )*
+                Some(($($x,)*))
+            }
+        }
+    );
+}
+
+impl_tuple_collect!(1; (A,); a);
+impl_tuple_collect!(2; (A, A); a, b);
+impl_tuple_collect!(3; (A, A, A); a, b, c);
+impl_tuple_collect!(4; (A, A, A, A); a, b, c, d);
+impl_tuple_collect!(5; (A, A, A, A, A); a, b, c, d, e);
+impl_tuple_collect!(6; (A, A, A, A, A, A); a, b, c, d, e, f);
+impl_tuple_collect!(7; (A, A, A, A, A, A, A); a, b, c, d, e, f, g);
+impl_tuple_collect!(8; (A, A, A, A, A, A, A, A); a, b, c, d, e, f, g, h);
+impl_tuple_collect!(9; (A, A, A, A, A, A, A, A, A); a, b, c, d, e, f, g, h, i);
+impl_tuple_collect!(10; (A, A, A, A, A, A, A, A, A, A); a, b, c, d, e, f, g, h, i, j);
+impl_tuple_collect!(11; (A, A, A, A, A, A, A, A, A, A, A); a, b, c, d, e, f, g, h, i, j, k);
+impl_tuple_collect!(12; (A, A, A, A, A, A, A, A, A, A, A, A); a, b, c, d, e, f, g, h, i, j, k, l);
+
+mod sealed {
+    pub trait Sealed<A> { }
+}
+
+/// Implemented for the primitive integer types and references to them,
+/// used for implementing *.checked_sum()* and *.checked_product()*.
+///
+/// This trait is sealed: it can not be implemented outside this crate.
+pub trait CheckedSummable<A = Self> : sealed::Sealed<A> + Sized {
+    #[doc(hidden)]
+    /// Sum the elements of **iter**, or return **None** on overflow.
+    fn checked_sum<I>(iter: I) -> Option<Self> where
+        I: Iterator<Item=A>;
+
+    #[doc(hidden)]
+    /// Multiply the elements of **iter**, or return **None** on overflow.
+    fn checked_product<I>(iter: I) -> Option<Self> where
+        I: Iterator<Item=A>;
+}
+
+macro_rules! impl_checked_summable {
+    ($($t:ty)*) => ($(
+        impl sealed::Sealed<$t> for $t { }
+        impl<'a> sealed::Sealed<&'a $t> for $t { }
+
+        impl CheckedSummable for $t {
+            fn checked_sum<I>(mut iter: I) -> Option<$t> where
+                I: Iterator<Item=$t>,
+            {
+                iter.try_fold(0, |acc: $t, x| acc.checked_add(x))
+            }
+
+            fn checked_product<I>(mut iter: I) -> Option<$t> where
+                I: Iterator<Item=$t>,
+            {
+                iter.try_fold(1, |acc: $t, x| acc.checked_mul(x))
+            }
+        }
+
+        impl<'a> CheckedSummable<&'a $t> for $t {
+            fn checked_sum<I>(iter: I) -> Option<$t> where
+                I: Iterator<Item=&'a $t>,
+            {
+                CheckedSummable::checked_sum(iter.cloned())
+            }
+
+            fn checked_product<I>(iter: I) -> Option<$t> where
+                I: Iterator<Item=&'a $t>,
+            {
+                CheckedSummable::checked_product(iter.cloned())
+            }
+        }
+    )*);
+}
+
+impl_checked_summable!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+impl<T, E> sealed::Sealed<Result<T, E>> for Result<T, E> { }
+
+/// Implemented for **Result**, to name its **Ok** and **Err** types from the
+/// element type of an iterator; used for implementing *.try_collect()*.
+///
+/// This trait is sealed: it can not be implemented outside this crate.
+pub trait IntoResult : sealed::Sealed<Self> + Sized {
+    /// The type of the **Ok** values.
+    type Ok;
+    /// The type of the **Err** values.
+    type Err;
+
+    #[doc(hidden)]
+    /// Convert into a **Result**.
+    fn into_result(self) -> Result<Self::Ok, Self::Err>;
+}
+
+impl<T, E> IntoResult for Result<T, E> {
+    type Ok = T;
+    type Err = E;
+
+    #[inline]
+    fn into_result(self) -> Result<T, E>
+    {
+        self
+    }
+}
@@ -0,0 +1,100 @@
+use core::cmp;
+use core::iter::{Fuse, FusedIterator};
+
+use size_hint;
+
+/// An iterator adaptor that pads a sequence to a minimum length by filling
+/// missing elements using a closure.
+///
+/// See [*.pad_using()*](trait.Itertools.html#method.pad_using) for more
+/// information.
+///
+/// Iterator element type is **I::Item**.
+#[derive(Clone)]
+pub struct PadUsing<I, F> {
+    iter: Fuse<I>,
+    min: usize,
+    pos: usize,
+    filler: F,
+}
+
+impl<I, F> PadUsing<I, F> where
+    I: Iterator,
+    F: FnMut(usize) -> I::Item,
+{
+    /// Create a new **PadUsing** iterator.
+    pub fn new(iter: I, min: usize, filler: F) -> Self
+    {
+        PadUsing {
+            iter: iter.fuse(),
+            min: min,
+            pos: 0,
+            filler: filler,
+        }
+    }
+}
+
+impl<I, F> Iterator for PadUsing<I, F> where
+    I: Iterator,
+    F: FnMut(usize) -> I::Item,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        match self.iter.next() {
+            None => {
+                if self.pos < self.min {
+                    let elt = (self.filler)(self.pos);
+                    self.pos += 1;
+                    Some(elt)
+                } else {
+                    None
+                }
+            }
+            elt => {
+                self.pos += 1;
+                elt
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let tail = self.min.saturating_sub(self.pos);
+        size_hint::max(self.iter.size_hint(), (tail, Some(tail)))
+    }
+}
+
+impl<I, F> DoubleEndedIterator for PadUsing<I, F> where
+    I: DoubleEndedIterator + ExactSizeIterator,
+    F: FnMut(usize) -> I::Item,
+{
+    fn next_back(&mut self) -> Option<I::Item>
+    {
+        // the padding is at positions pos + len .. min, so it comes first
+        // from the back; lowering min keeps the front in step either way
+        if self.pos.saturating_add(self.iter.len()) >= self.min {
+            self.min = self.min.saturating_sub(1);
+            self.iter.next_back()
+        } else {
+            self.min -= 1;
+            Some((self.filler)(self.min))
+        }
+    }
+}
+
+impl<I, F> ExactSizeIterator for PadUsing<I, F> where
+    I: ExactSizeIterator,
+    F: FnMut(usize) -> I::Item,
+{
+    fn len(&self) -> usize
+    {
+        cmp::max(self.iter.len(), self.min.saturating_sub(self.pos))
+    }
+}
+
+impl<I, F> FusedIterator for PadUsing<I, F> where
+    I: Iterator,
+    F: FnMut(usize) -> I::Item,
+{ }
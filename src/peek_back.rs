@@ -0,0 +1,86 @@
+use size_hint;
+
+/// An iterator adaptor that can peek at the back element of a double ended
+/// iterator without consuming it.
+///
+/// See [*peekable_back()*](fn.peekable_back.html) for more information.
+///
+/// Iterator element type is **I::Item**.
+#[derive(Clone, Debug)]
+pub struct PeekBack<I> where
+    I: Iterator,
+{
+    iter: I,
+    back: Option<I::Item>,
+}
+
+/// Create an iterator adaptor that can peek at the back of a double ended
+/// iterator with *.peek_back()*.
+///
+/// The peeked element is buffered, so a later *.next_back()* still returns
+/// it, and so does *.next()* once the front reaches it.
+///
+/// ```
+/// use itertools::peekable_back;
+///
+/// let mut it = peekable_back(0..5);
+/// assert_eq!(it.peek_back(), Some(&4));
+/// assert_eq!(it.next_back(), Some(4));
+/// assert_eq!(it.next(), Some(0));
+/// ```
+pub fn peekable_back<I>(iterable: I) -> PeekBack<I::IntoIter> where
+    I: IntoIterator,
+    I::IntoIter: DoubleEndedIterator,
+{
+    PeekBack{iter: iterable.into_iter(), back: None}
+}
+
+impl<I> PeekBack<I> where
+    I: DoubleEndedIterator,
+{
+    /// Return a reference to the element that *.next_back()* will return,
+    /// without advancing the iterator.
+    pub fn peek_back(&mut self) -> Option<&I::Item>
+    {
+        if self.back.is_none() {
+            self.back = self.iter.next_back();
+        }
+        self.back.as_ref()
+    }
+}
+
+impl<I> Iterator for PeekBack<I> where
+    I: Iterator,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        // the peeked element is the last one left once the front reaches it
+        match self.iter.next() {
+            None => self.back.take(),
+            elt => elt,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        size_hint::add_scalar(self.iter.size_hint(), self.back.is_some() as usize)
+    }
+}
+
+impl<I> DoubleEndedIterator for PeekBack<I> where
+    I: DoubleEndedIterator,
+{
+    fn next_back(&mut self) -> Option<I::Item>
+    {
+        match self.back.take() {
+            None => self.iter.next_back(),
+            elt => elt,
+        }
+    }
+}
+
+impl<I> ExactSizeIterator for PeekBack<I> where
+    I: ExactSizeIterator,
+{ }
@@ -0,0 +1,142 @@
+use core::iter::Peekable;
+use core::slice;
+use core::str;
+use PutBack;
+#[cfg(feature = "use_std")]
+use PutBackN;
+
+/// An iterator that allows peeking at an element before deciding to accept it.
+///
+/// See [*.peeking_take_while()*](trait.Itertools.html#method.peeking_take_while)
+/// for more information.
+pub trait PeekingNext : Iterator {
+    /// Pass a reference to the next iterator element to the closure **accept**;
+    /// if **accept** returns **true**, return it as **Some(elt)**, otherwise
+    /// leave it in the iterator and return **None**.
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item> where
+        F: FnOnce(&Self::Item) -> bool;
+}
+
+impl<I> PeekingNext for Peekable<I> where
+    I: Iterator,
+{
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item> where
+        F: FnOnce(&Self::Item) -> bool,
+    {
+        if let Some(r) = self.peek() {
+            if !accept(r) {
+                return None
+            }
+        }
+        self.next()
+    }
+}
+
+impl<I> PeekingNext for PutBack<I> where
+    I: Iterator,
+{
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item> where
+        F: FnOnce(&Self::Item) -> bool,
+    {
+        match self.next() {
+            None => None,
+            Some(r) => {
+                if !accept(&r) {
+                    self.put_back(r);
+                    return None
+                }
+                Some(r)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl<I> PeekingNext for PutBackN<I> where
+    I: Iterator,
+{
+    fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item> where
+        F: FnOnce(&Self::Item) -> bool,
+    {
+        match self.next() {
+            None => None,
+            Some(r) => {
+                if !accept(&r) {
+                    self.put_back(r);
+                    return None
+                }
+                Some(r)
+            }
+        }
+    }
+}
+
+macro_rules! peeking_next_by_clone {
+    ([$($typarm:tt)*] $type_:ty) => {
+        impl<$($typarm)*> PeekingNext for $type_ {
+            fn peeking_next<F>(&mut self, accept: F) -> Option<Self::Item> where
+                F: FnOnce(&Self::Item) -> bool,
+            {
+                let saved_state = self.clone();
+                match self.next() {
+                    None => None,
+                    Some(elt) => {
+                        if !accept(&elt) {
+                            *self = saved_state;
+                            return None
+                        }
+                        Some(elt)
+                    }
+                }
+            }
+        }
+    }
+}
+
+peeking_next_by_clone! { ['a, T] slice::Iter<'a, T> }
+peeking_next_by_clone! { ['a] str::Chars<'a> }
+peeking_next_by_clone! { ['a] str::CharIndices<'a> }
+peeking_next_by_clone! { ['a] str::Bytes<'a> }
+
+/// An iterator adaptor that takes items while a closure returns **true**.
+///
+/// See [*.peeking_take_while()*](trait.Itertools.html#method.peeking_take_while)
+/// for more information.
+pub struct PeekingTakeWhile<'a, I: 'a, F> where
+    I: Iterator,
+{
+    iter: &'a mut I,
+    f: F,
+}
+
+impl<'a, I, F> PeekingTakeWhile<'a, I, F> where
+    I: Iterator,
+{
+    /// Create a new **PeekingTakeWhile**.
+    pub fn new(iter: &'a mut I, f: F) -> Self
+    {
+        PeekingTakeWhile {
+            iter: iter,
+            f: f,
+        }
+    }
+}
+
+impl<'a, I, F> Iterator for PeekingTakeWhile<'a, I, F> where
+    I: PeekingNext,
+    F: FnMut(&I::Item) -> bool,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let f = &mut self.f;
+        self.iter.peeking_next(|r| f(r))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let (_, hi) = self.iter.size_hint();
+        (0, hi)
+    }
+}
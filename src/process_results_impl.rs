@@ -0,0 +1,91 @@
+/// An iterator adaptor over the **Ok** values of an iterator of **Result**s,
+/// which stops at the first **Err** and stores it.
+///
+/// Passed to the closure of [*process_results()*](fn.process_results.html).
+///
+/// Iterator element type is **T**.
+#[derive(Debug)]
+pub struct ProcessResults<'a, I, E: 'a> {
+    error: &'a mut Result<(), E>,
+    iter: I,
+}
+
+impl<'a, I, T, E> Iterator for ProcessResults<'a, I, E> where
+    I: Iterator<Item=Result<T, E>>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T>
+    {
+        if self.error.is_err() {
+            return None;
+        }
+        match self.iter.next() {
+            Some(Ok(x)) => Some(x),
+            Some(Err(e)) => {
+                *self.error = Err(e);
+                None
+            }
+            None => None,
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        if self.error.is_err() {
+            return (0, Some(0));
+        }
+        // any element may be the error
+        (0, self.iter.size_hint().1)
+    }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B where
+        F: FnMut(B, T) -> B,
+    {
+        if self.error.is_err() {
+            return init;
+        }
+        let error = self.error;
+        let res = self.iter.try_fold(init, |acc, elt| {
+            match elt {
+                Ok(x) => Ok(f(acc, x)),
+                Err(e) => {
+                    *error = Err(e);
+                    Err(acc)
+                }
+            }
+        });
+        match res {
+            Ok(acc) | Err(acc) => acc,
+        }
+    }
+}
+
+/// “Lift” a function of the values of an iterator so that it can process
+/// an iterator of **Result** values instead.
+///
+/// **processor** is a closure that receives an adapted version of the
+/// iterable as its only argument — the adapted iterator yields the **Ok**
+/// values and stops at the first **Err**. The result of **processor** is
+/// returned as **Ok**, unless an error was encountered, in which case the
+/// error is returned instead.
+///
+/// See also [*.process_results()*](trait.Itertools.html#method.process_results).
+///
+/// ```
+/// use itertools::process_results;
+///
+/// let good = vec![Ok::<_, ()>(1), Ok(3), Ok(2)];
+/// let bad = vec![Ok(1), Err("bad"), Ok(2)];
+///
+/// assert_eq!(process_results(good, |it| it.max()), Ok(Some(3)));
+/// assert_eq!(process_results(bad, |it| it.max()), Err("bad"));
+/// ```
+pub fn process_results<I, F, T, E, R>(iterable: I, processor: F) -> Result<R, E> where
+    I: IntoIterator<Item=Result<T, E>>,
+    F: FnOnce(ProcessResults<I::IntoIter, E>) -> R,
+{
+    let mut error = Ok(());
+    let result = processor(ProcessResults{error: &mut error, iter: iterable.into_iter()});
+    error.map(|_| result)
+}
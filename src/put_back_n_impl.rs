@@ -0,0 +1,93 @@
+use std::collections::VecDeque;
+
+use size_hint;
+
+/// An iterator adaptor that allows putting multiple
+/// items in front of the iterator.
+///
+/// Iterator element type is **I::Item**.
+#[derive(Debug, Clone)]
+pub struct PutBackN<I> where
+    I: Iterator,
+{
+    top: VecDeque<I::Item>,
+    iter: I,
+}
+
+/// Create an iterator where you can put back multiple values to the front
+/// of the iteration.
+///
+/// Iterator element type is **I::Item**.
+///
+/// ```
+/// use itertools::put_back_n;
+///
+/// let mut it = put_back_n(1..4);
+/// it.put_back(0);
+/// it.put_back(-1);
+/// assert!(itertools::equal(it, vec![-1, 0, 1, 2, 3]));
+/// ```
+pub fn put_back_n<I>(iterable: I) -> PutBackN<I::IntoIter> where
+    I: IntoIterator,
+{
+    PutBackN {
+        top: VecDeque::new(),
+        iter: iterable.into_iter(),
+    }
+}
+
+impl<I> PutBackN<I> where
+    I: Iterator,
+{
+    /// Put back a single value to the front of the iterator.
+    ///
+    /// Values are returned in the reverse order of being put back: the
+    /// most recently put back value is the next one to be returned.
+    #[inline]
+    pub fn put_back(&mut self, x: I::Item)
+    {
+        self.top.push_back(x);
+    }
+}
+
+impl<I> Iterator for PutBackN<I> where
+    I: Iterator,
+{
+    type Item = I::Item;
+    #[inline]
+    fn next(&mut self) -> Option<I::Item>
+    {
+        match self.top.pop_back() {
+            None => self.iter.next(),
+            some => some,
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        size_hint::add_scalar(self.iter.size_hint(), self.top.len())
+    }
+}
+
+/// The put back elements are in front of the iterator, so they are yielded
+/// last from the back, in the order they were put back.
+impl<I> DoubleEndedIterator for PutBackN<I> where
+    I: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<I::Item>
+    {
+        match self.iter.next_back() {
+            None => self.top.pop_front(),
+            elt => elt,
+        }
+    }
+}
+
+/// The length is that of the base iterator plus the put back elements. If
+/// that overflows **usize**, the size hint saturates to
+/// **(usize::MAX, None)** and *.len()* panics.
+impl<I> ExactSizeIterator for PutBackN<I> where
+    I: ExactSizeIterator,
+{ }
@@ -18,6 +18,42 @@ impl<I> RcIter<I>
     }
 }
 
+/// Return an iterator inside a **Rc\<RefCell\<_\>\>** wrapper.
+///
+/// The returned **RcIter** can be cloned, and each clone will refer back to the
+/// same original iterator. Every element is yielded by exactly one of the
+/// handles: a *.next()* on any handle advances the shared iterator.
+///
+/// **RcIter** allows doing interesting things like using **.zip()** on an
+/// iterator with itself, at the cost of runtime borrow checking.
+///
+/// Iterator element type is **Self::Item**.
+///
+/// ## Example
+///
+/// ```
+/// use itertools::rciter;
+///
+/// let rit = rciter(0..6);
+/// let pairs = rit.clone().zip(rit).collect::<Vec<_>>();
+/// assert_eq!(pairs, vec![(0, 1), (2, 3), (4, 5)]);
+/// ```
+///
+/// Calling *.next()* on two handles within the same expression, like
+/// **(a.next(), b.next())**, is allowed because each borrow ends when *.next()*
+/// returns; which handle receives which element then depends on the order
+/// of evaluation.
+///
+/// **Panics** in iterator methods if a borrow error is encountered,
+/// which can only happen if the **RcIter** is reentered while it is already
+/// borrowed, for example if it is part of the iterator it is wrapping,
+/// in an “iterator knot”.
+pub fn rciter<I>(iterable: I) -> RcIter<I::IntoIter> where
+    I: IntoIterator,
+{
+    RcIter::new(iterable.into_iter())
+}
+
 impl<I> Clone for RcIter<I>
 {
     #[inline]
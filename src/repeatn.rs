@@ -1,3 +1,24 @@
+/// Create an iterator that produces **n** repetitions of **element**.
+///
+/// The element is cloned **n - 1** times and moved out on the last
+/// iteration. If **n** is zero, the element is dropped immediately.
+///
+/// Iterator element type is **A**.
+///
+/// ```
+/// use itertools;
+///
+/// let mut it = itertools::repeat_n("x", 2);
+/// assert_eq!(it.len(), 2);
+/// assert_eq!(it.next(), Some("x"));
+/// assert_eq!(it.next(), Some("x"));
+/// assert_eq!(it.next(), None);
+/// ```
+pub fn repeat_n<A>(element: A, n: usize) -> RepeatN<A> where
+    A: Clone,
+{
+    RepeatN::new(element, n)
+}
 
 /// An iterator that repeats an element exactly *n* times.
 pub struct RepeatN<A>
@@ -0,0 +1,95 @@
+
+use std::iter::IntoIterator;
+use std::sync::{Arc, Mutex};
+
+/// A wrapper for **Arc\<Mutex\<I\>\>**, that implements the **Iterator** trait.
+///
+/// This is the thread safe counterpart of **RcIter**: it is **Send** and
+/// **Sync** when **I** is **Send**.
+pub struct SharedIter<I> {
+    /// The shared iterator.
+    pub iter: Arc<Mutex<I>>,
+}
+
+impl<I> SharedIter<I>
+{
+    /// Create a new SharedIter.
+    pub fn new(iter: I) -> SharedIter<I>
+    {
+        SharedIter{iter: Arc::new(Mutex::new(iter))}
+    }
+}
+
+/// Return an iterator inside an **Arc\<Mutex\<_\>\>** wrapper.
+///
+/// The returned **SharedIter** can be cloned and the clones sent to other
+/// threads, and each clone will refer back to the same original iterator.
+/// Every element is yielded by exactly one of the handles: each *.next()*
+/// locks the mutex and advances the shared iterator.
+///
+/// **SharedIter** is not double ended: with several threads pulling from
+/// both ends, neither end could be relied on.
+///
+/// Iterator element type is **Self::Item**.
+///
+/// ## Example
+///
+/// ```
+/// use std::thread;
+/// use itertools::sharediter;
+///
+/// let it = sharediter(0..100);
+/// let handles: Vec<_> = (0..4).map(|_| {
+///     let it = it.clone();
+///     thread::spawn(move || it.sum::<i32>())
+/// }).collect();
+/// let total: i32 = handles.into_iter().map(|h| h.join().unwrap()).sum();
+/// assert_eq!(total, (0..100).sum());
+/// ```
+///
+/// **Panics** in iterator methods if the mutex is poisoned, which happens if
+/// a thread panicked while it was advancing the shared iterator.
+pub fn sharediter<I>(iterable: I) -> SharedIter<I::IntoIter> where
+    I: IntoIterator,
+{
+    SharedIter::new(iterable.into_iter())
+}
+
+impl<I> Clone for SharedIter<I>
+{
+    #[inline]
+    fn clone(&self) -> SharedIter<I> {
+        SharedIter{iter: self.iter.clone()}
+    }
+}
+
+impl<A, I> Iterator for SharedIter<I> where
+    I: Iterator<Item=A>,
+{
+    type Item = A;
+    #[inline]
+    fn next(&mut self) -> Option<A>
+    {
+        self.iter.lock().expect("SharedIter: poisoned mutex").next()
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Like RcIter, other clones may drain values under our feet.
+        let (_, hi) = self.iter.lock().expect("SharedIter: poisoned mutex").size_hint();
+        (0, hi)
+    }
+}
+
+/// Return an iterator from **&SharedIter\<I\>** (by simply cloning it).
+impl<'a, I> IntoIterator for &'a SharedIter<I> where
+    I: Iterator,
+{
+    type Item = I::Item;
+    type IntoIter = SharedIter<I>;
+
+    fn into_iter(self) -> SharedIter<I>
+    {
+        self.clone()
+    }
+}
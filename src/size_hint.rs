@@ -1,8 +1,8 @@
 //! Arithmetic on **Iterator** *.size_hint()* values.
 //!
 
-use std::usize;
-use std::cmp;
+use core::usize;
+use core::cmp;
 
 /// **SizeHint** is the return type of **Iterator::size_hint()**.
 pub type SizeHint = (usize, Option<usize>);
@@ -17,6 +17,29 @@ pub fn add_scalar(sh: SizeHint, x: usize) -> SizeHint
     (low, hi)
 }
 
+/// Subtract **x** correctly from a **SizeHint**, saturating at zero.
+///
+/// # Examples
+///
+/// ```
+/// use itertools::size_hint;
+///
+/// assert_eq!(size_hint::sub_scalar((3, Some(4)), 2),
+///            (1, Some(2)));
+/// assert_eq!(size_hint::sub_scalar((3, Some(4)), 5),
+///            (0, Some(0)));
+/// assert_eq!(size_hint::sub_scalar((3, None), 0),
+///            (3, None));
+/// ```
+#[inline]
+pub fn sub_scalar(sh: SizeHint, x: usize) -> SizeHint
+{
+    let (mut low, mut hi) = sh;
+    low = low.saturating_sub(x);
+    hi = hi.map(|elt| elt.saturating_sub(x));
+    (low, hi)
+}
+
 /// Add **SizeHint** correctly.
 #[inline]
 pub fn add(a: SizeHint, b: SizeHint) -> SizeHint
@@ -56,6 +79,62 @@ pub fn mul_scalar(sh: SizeHint, x: usize) -> SizeHint
     (low, hi)
 }
 
+/// Divide a **SizeHint** by **x**, rounding both bounds up: the size hint of
+/// a sequence split into pieces of **x** elements, with a shorter last piece.
+///
+/// **Panics** if **x** is zero.
+///
+/// # Examples
+///
+/// ```
+/// use itertools::size_hint;
+///
+/// assert_eq!(size_hint::div_scalar_ceil((9, Some(10)), 3),
+///            (3, Some(4)));
+/// assert_eq!(size_hint::div_scalar_ceil((0, None), 3),
+///            (0, None));
+/// ```
+#[inline]
+pub fn div_scalar_ceil(sh: SizeHint, x: usize) -> SizeHint
+{
+    let div_ceil = |n: usize| n / x + (n % x != 0) as usize;
+    let (low, hi) = sh;
+    (div_ceil(low), hi.map(div_ceil))
+}
+
+/// Raise a **SizeHint** to the power **exp**, the size hint of a product
+/// of **exp** iterators with the same size hint.
+///
+/// # Examples
+///
+/// ```
+/// use std::usize;
+/// use itertools::size_hint;
+///
+/// assert_eq!(size_hint::pow((2, Some(3)), 3),
+///            (8, Some(27)));
+/// assert_eq!(size_hint::pow((2, Some(3)), 0),
+///            (1, Some(1)));
+/// assert_eq!(size_hint::pow((0, Some(0)), 2),
+///            (0, Some(0)));
+/// assert_eq!(size_hint::pow((3, Some(usize::MAX / 2)), 2),
+///            (9, None));
+/// assert_eq!(size_hint::pow((usize::MAX, None), 2),
+///            (usize::MAX, None));
+/// ```
+#[inline]
+pub fn pow(sh: SizeHint, exp: u32) -> SizeHint
+{
+    let (mut low, mut hi) = sh;
+    low = low.checked_pow(exp).unwrap_or(usize::MAX);
+    if exp == 0 {
+        hi = Some(1)
+    } else {
+        hi = hi.and_then(|elt| elt.checked_pow(exp));
+    }
+    (low, hi)
+}
+
 /// Multiply **SizeHint** correctly
 ///
 /// # Examples
@@ -0,0 +1,82 @@
+use core::iter::FusedIterator;
+use std::vec::{self, Vec};
+
+/// An iterator over the elements of a sorted **Vec**, created with
+/// *.sorted()*, *.sorted_unstable()* and their variants.
+///
+/// All the iterator methods forward to the owning iterator of the **Vec**,
+/// so it is double ended and has an exact size.
+///
+/// Iterator element type is **T**.
+#[derive(Clone, Debug)]
+pub struct SortedIter<T> {
+    iter: vec::IntoIter<T>,
+}
+
+impl<T> SortedIter<T> {
+    /// Create a new **SortedIter** iterating the elements of **v**, which
+    /// should already be sorted.
+    pub fn new(v: Vec<T>) -> Self
+    {
+        SortedIter{iter: v.into_iter()}
+    }
+}
+
+impl<T> Iterator for SortedIter<T> {
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T>
+    {
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T>
+    {
+        self.iter.nth(n)
+    }
+
+    fn count(self) -> usize
+    {
+        self.iter.count()
+    }
+
+    fn last(self) -> Option<T>
+    {
+        self.iter.last()
+    }
+
+    fn fold<B, F>(self, init: B, f: F) -> B where
+        F: FnMut(B, T) -> B,
+    {
+        self.iter.fold(init, f)
+    }
+}
+
+impl<T> DoubleEndedIterator for SortedIter<T> {
+    #[inline]
+    fn next_back(&mut self) -> Option<T>
+    {
+        self.iter.next_back()
+    }
+
+    fn rfold<B, F>(self, init: B, f: F) -> B where
+        F: FnMut(B, T) -> B,
+    {
+        self.iter.rfold(init, f)
+    }
+}
+
+impl<T> ExactSizeIterator for SortedIter<T> {
+    fn len(&self) -> usize
+    {
+        self.iter.len()
+    }
+}
+
+impl<T> FusedIterator for SortedIter<T> { }
@@ -0,0 +1,261 @@
+//! Iterators that are sources (produce elements from parameters,
+//! not from another iterator).
+
+use core::fmt;
+
+/// An iterator that calls a function to produce each element, forever.
+///
+/// Created with the *repeat_call()* function.
+///
+/// Iterator element type is **A**.
+#[derive(Clone)]
+pub struct RepeatCall<F> {
+    f: F,
+}
+
+impl<F> fmt::Debug for RepeatCall<F>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.debug_struct("RepeatCall").finish()
+    }
+}
+
+/// Create an iterator that calls **function** to produce each element,
+/// without end.
+///
+/// This is useful for drawing values from a stateful source, such as a
+/// counter or a random number generator.
+///
+/// Iterator element type is **A**, the return type of **F**.
+///
+/// ```
+/// use itertools::repeat_call;
+///
+/// let mut n = 0;
+/// let squares = repeat_call(|| { n += 1; n * n });
+/// assert!(itertools::equal(squares.take(4), vec![1, 4, 9, 16]));
+/// ```
+pub fn repeat_call<F, A>(function: F) -> RepeatCall<F> where
+    F: FnMut() -> A,
+{
+    RepeatCall{f: function}
+}
+
+impl<A, F> Iterator for RepeatCall<F> where
+    F: FnMut() -> A,
+{
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<A>
+    {
+        Some((self.f)())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (::core::usize::MAX, None)
+    }
+}
+
+/// An iterator that calls a function with the indices 0, 1, 2, ... to
+/// produce its elements.
+///
+/// Created with the *tabulate()* function.
+///
+/// Iterator element type is **T**.
+#[derive(Clone)]
+pub struct Tabulate<F> {
+    index: usize,
+    f: F,
+}
+
+impl<F> fmt::Debug for Tabulate<F>
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.debug_struct("Tabulate")
+         .field("index", &self.index)
+         .finish()
+    }
+}
+
+/// Create an iterator of **function** tabulated over the natural numbers:
+/// **function(0)**, **function(1)**, **function(2)**, and so on.
+///
+/// Iterator element type is **T**, the return type of **F**.
+///
+/// ```
+/// use itertools::tabulate;
+///
+/// let squares = tabulate(|i| i * i);
+/// assert!(itertools::equal(squares.take(4), vec![0, 1, 4, 9]));
+/// ```
+pub fn tabulate<T, F>(function: F) -> Tabulate<F> where
+    F: FnMut(usize) -> T,
+{
+    Tabulate{index: 0, f: function}
+}
+
+impl<T, F> Iterator for Tabulate<F> where
+    F: FnMut(usize) -> T,
+{
+    type Item = T;
+
+    #[inline]
+    fn next(&mut self) -> Option<T>
+    {
+        let elt = (self.f)(self.index);
+        self.index += 1;
+        Some(elt)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (::core::usize::MAX, None)
+    }
+}
+
+/// An iterator that infinitely applies a function to a value, yielding
+/// each intermediate result.
+///
+/// Created with the *iterate()* function.
+///
+/// Iterator element type is **St**.
+#[derive(Clone)]
+pub struct Iterate<St, F> {
+    state: St,
+    f: F,
+    started: bool,
+}
+
+impl<St, F> fmt::Debug for Iterate<St, F> where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.debug_struct("Iterate")
+         .field("state", &self.state)
+         .field("started", &self.started)
+         .finish()
+    }
+}
+
+impl<St, F> Iterator for Iterate<St, F> where
+    St: Clone,
+    F: FnMut(&St) -> St,
+{
+    type Item = St;
+
+    #[inline]
+    fn next(&mut self) -> Option<St>
+    {
+        if self.started {
+            self.state = (self.f)(&self.state);
+        } else {
+            self.started = true;
+        }
+        Some(self.state.clone())
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        (::core::usize::MAX, None)
+    }
+}
+
+/// Create an iterator that yields **initial_value** first, and then each
+/// result of applying **f** to the previous element, forever.
+///
+/// **f** is called lazily: only when the next element is requested, so
+/// taking **k + 1** elements calls it **k** times.
+///
+/// Iterator element type is **St**.
+///
+/// ```
+/// use itertools::iterate;
+///
+/// assert!(itertools::equal(iterate(1, |&x| x * 2).take(5), vec![1, 2, 4, 8, 16]));
+/// ```
+pub fn iterate<St, F>(initial_value: St, f: F) -> Iterate<St, F> where
+    St: Clone,
+    F: FnMut(&St) -> St,
+{
+    Iterate {
+        state: initial_value,
+        f: f,
+        started: false,
+    }
+}
+
+/// An iterator that produces elements from a closure that mutates a
+/// state, until the closure returns **None**.
+///
+/// Created with the *unfold()* function.
+///
+/// Iterator element type is **A**.
+#[derive(Clone)]
+pub struct Unfold<St, F> {
+    f: F,
+    /// Internal state that will be passed to the closure on the next iteration
+    pub state: St,
+}
+
+impl<St, F> fmt::Debug for Unfold<St, F> where
+    St: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.debug_struct("Unfold")
+         .field("state", &self.state)
+         .finish()
+    }
+}
+
+impl<A, St, F> Iterator for Unfold<St, F> where
+    F: FnMut(&mut St) -> Option<A>,
+{
+    type Item = A;
+
+    #[inline]
+    fn next(&mut self) -> Option<A>
+    {
+        (self.f)(&mut self.state)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        // no possible known bounds at this point
+        (0, None)
+    }
+}
+
+/// Create an iterator from a state and a closure that computes each
+/// element from a mutable reference to that state.
+///
+/// The closure returns **Some(element)** to continue, or **None** to end
+/// the iterator. Unlike *iterate()*, the state and element types may
+/// differ.
+///
+/// Iterator element type is **A**, where **F** returns **Option\<A\>**.
+///
+/// ```
+/// use itertools::unfold;
+///
+/// // a worklist that is drained from the back
+/// let drained = unfold(vec![1, 2, 3], |stack| stack.pop());
+/// assert!(itertools::equal(drained, vec![3, 2, 1]));
+/// ```
+pub fn unfold<A, St, F>(initial_state: St, f: F) -> Unfold<St, F> where
+    F: FnMut(&mut St) -> Option<A>,
+{
+    Unfold {
+        f: f,
+        state: initial_state,
+    }
+}
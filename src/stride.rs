@@ -4,10 +4,10 @@
 //! option. This file may not be copied, modified, or distributed
 //! except according to those terms.
 
-use std::fmt;
-use std::marker;
-use std::mem;
-use std::ops::{Index, IndexMut};
+use core::fmt;
+use core::marker;
+use core::mem;
+use core::ops::{Index, IndexMut};
 
 /// An iterator similar to the slice iterator, but with a certain number of steps
 /// (the stride) skipped per iteration.
@@ -3,6 +3,7 @@ use super::size_hint;
 use std::cell::RefCell;
 use std::collections::VecDeque;
 use std::rc::Rc;
+use std::vec::Vec;
 
 /// Common buffer object for the two tee halves
 struct TeeBuffer<A, I>
@@ -74,3 +75,125 @@ impl<I> ExactSizeIterator for Tee<I> where
     I: ExactSizeIterator,
     I::Item: Clone,
 { }
+
+/// Common buffer object for all handles created by *.tee_n()*
+struct TeeNBuffer<A, I>
+{
+    backlog: VecDeque<A>,
+    /// Stream position of the front element of the backlog
+    base: usize,
+    iter: I,
+    /// Stream position of each handle, or **None** if it was dropped
+    offsets: Vec<Option<usize>>,
+}
+
+impl<A, I> TeeNBuffer<A, I>
+{
+    /// Drop elements from the backlog that every live handle has already read.
+    fn trim(&mut self)
+    {
+        let min = self.offsets.iter().filter_map(|x| *x).min();
+        let min = min.unwrap_or(self.base + self.backlog.len());
+        while self.base < min {
+            self.backlog.pop_front();
+            self.base += 1;
+        }
+    }
+}
+
+/// One of several handles to an iterator where all handles return the same elements.
+///
+/// Created with *.tee_n()*.
+pub struct TeeHandle<I> where
+    I: Iterator
+{
+    rcbuffer: Rc<RefCell<TeeNBuffer<I::Item, I>>>,
+    id: usize,
+}
+
+pub fn new_n<I>(iter: I, n: usize) -> Vec<TeeHandle<I>> where
+    I: Iterator
+{
+    let buffer = TeeNBuffer {
+        backlog: VecDeque::new(),
+        base: 0,
+        iter: iter,
+        offsets: vec![Some(0); n],
+    };
+    let rcbuffer = Rc::new(RefCell::new(buffer));
+    (0..n).map(|id| TeeHandle{rcbuffer: rcbuffer.clone(), id: id}).collect()
+}
+
+impl<I> TeeHandle<I> where
+    I: Iterator
+{
+    /// Return the number of elements currently held in the shared buffer,
+    /// waiting for the slowest live handle to read them.
+    pub fn buffer_len(&self) -> usize
+    {
+        self.rcbuffer.borrow().backlog.len()
+    }
+}
+
+impl<I> Iterator for TeeHandle<I> where
+    I: Iterator,
+    I::Item: Clone,
+{
+    type Item = I::Item;
+    fn next(&mut self) -> Option<I::Item>
+    {
+        let mut buffer = self.rcbuffer.borrow_mut();
+        let buffer = &mut *buffer;
+        let offset = buffer.offsets[self.id].unwrap();
+        let index = offset - buffer.base;
+        let elt = if index < buffer.backlog.len() {
+            buffer.backlog[index].clone()
+        } else {
+            let elt = match buffer.iter.next() {
+                None => return None,
+                Some(elt) => elt,
+            };
+            // Only buffer the element if some other handle will read it
+            let id = self.id;
+            let shared = buffer.offsets.iter().enumerate()
+                               .any(|(i, x)| i != id && x.is_some());
+            if !shared {
+                buffer.base += 1;
+                buffer.offsets[id] = Some(offset + 1);
+                return Some(elt)
+            }
+            buffer.backlog.push_back(elt.clone());
+            elt
+        };
+        buffer.offsets[self.id] = Some(offset + 1);
+        buffer.trim();
+        Some(elt)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let buffer = self.rcbuffer.borrow();
+        let sh = buffer.iter.size_hint();
+        let offset = buffer.offsets[self.id].unwrap();
+        let log_len = buffer.backlog.len() - (offset - buffer.base);
+        size_hint::add_scalar(sh, log_len)
+    }
+}
+
+impl<I> ExactSizeIterator for TeeHandle<I> where
+    I: ExactSizeIterator,
+    I::Item: Clone,
+{ }
+
+impl<I> Drop for TeeHandle<I> where
+    I: Iterator
+{
+    fn drop(&mut self)
+    {
+        // Deregister so that this handle doesn't hold on to the buffer.
+        if let Ok(mut buffer) = self.rcbuffer.try_borrow_mut() {
+            buffer.offsets[self.id] = None;
+            buffer.trim();
+        }
+    }
+}
@@ -0,0 +1,231 @@
+use std::iter::Fuse;
+use std::marker::PhantomData;
+use std::vec::Vec;
+use super::misc::TupleCollect;
+
+/// An iterator to iterate through all combinations of the elements of an
+/// iterator, yielded as tuples of a fixed size.
+///
+/// The source iterator is buffered in full on the first call to *.next()*.
+/// *.nth()* computes the combination at the requested position directly,
+/// without producing the ones in between.
+///
+/// Iterator element type is **T**.
+pub struct TupleCombinations<I, T> where
+    I: Iterator,
+{
+    iter: Fuse<I>,
+    pool: Vec<I::Item>,
+    indices: Vec<usize>,
+    first: bool,
+    done: bool,
+    tuple: PhantomData<T>,
+}
+
+impl<I, T> TupleCombinations<I, T> where
+    I: Iterator,
+    T: TupleCollect<Item=I::Item>,
+{
+    /// Create a new **TupleCombinations**.
+    pub fn new(iter: I) -> Self
+    {
+        TupleCombinations {
+            iter: iter.fuse(),
+            pool: Vec::new(),
+            indices: (0..T::num_items()).collect(),
+            first: true,
+            done: false,
+            tuple: PhantomData,
+        }
+    }
+}
+
+impl<I, T> Clone for TupleCombinations<I, T> where
+    I: Iterator + Clone,
+    I::Item: Clone,
+{
+    fn clone(&self) -> Self
+    {
+        TupleCombinations {
+            iter: self.iter.clone(),
+            pool: self.pool.clone(),
+            indices: self.indices.clone(),
+            first: self.first,
+            done: self.done,
+            tuple: PhantomData,
+        }
+    }
+}
+
+/// Return the binomial coefficient *n choose k*, or **None** on overflow.
+fn checked_binomial(n: usize, k: usize) -> Option<usize>
+{
+    if k > n {
+        return Some(0)
+    }
+    let k = if k > n - k { n - k } else { k };
+    let mut c = 1usize;
+    for i in 0..k {
+        // c * (n - i) is always divisible by i + 1
+        c = match c.checked_mul(n - i) {
+            None => return None,
+            Some(x) => x / (i + 1),
+        };
+    }
+    Some(c)
+}
+
+/// Count the combinations of **n** elements that come after **indices**
+/// in lexicographic order, or return **None** on overflow.
+fn checked_remaining(n: usize, indices: &[usize]) -> Option<usize>
+{
+    let k = indices.len();
+    let mut count = Some(0usize);
+    for (i, &index) in indices.iter().enumerate() {
+        count = count.and_then(|c| {
+            checked_binomial(n - 1 - index, k - i)
+                .and_then(|x| c.checked_add(x))
+        });
+    }
+    count
+}
+
+/// Return the indices of the combination of **k** out of **n** elements
+/// with lexicographic position **rank** (which must be less than
+/// *n choose k*), or **None** on overflow.
+fn checked_unrank(n: usize, k: usize, mut rank: usize) -> Option<Vec<usize>>
+{
+    let mut indices = Vec::with_capacity(k);
+    let mut c = 0;
+    for i in 0..k {
+        // skip over all the combinations that have a smaller element at i
+        loop {
+            let with_c = match checked_binomial(n - 1 - c, k - 1 - i) {
+                None => return None,
+                Some(x) => x,
+            };
+            if rank < with_c {
+                break
+            }
+            rank -= with_c;
+            c += 1;
+        }
+        indices.push(c);
+        c += 1;
+    }
+    Some(indices)
+}
+
+impl<I, T> Iterator for TupleCombinations<I, T> where
+    I: Iterator,
+    I::Item: Clone,
+    T: TupleCollect<Item=I::Item>,
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<T>
+    {
+        if self.done {
+            return None
+        }
+        let n = self.pool.len();
+        let k = self.indices.len();
+        if self.first {
+            self.first = false;
+            self.pool.extend(self.iter.by_ref());
+            if k > self.pool.len() {
+                self.done = true;
+                return None
+            }
+        } else {
+            // find the rightmost index that can still be incremented
+            let mut i = k;
+            loop {
+                if i == 0 {
+                    self.done = true;
+                    return None
+                }
+                i -= 1;
+                if self.indices[i] < n - k + i {
+                    break
+                }
+            }
+            self.indices[i] += 1;
+            for j in i + 1..k {
+                self.indices[j] = self.indices[j - 1] + 1;
+            }
+        }
+        let pool = &self.pool;
+        T::collect_from_iter(self.indices.iter().map(|&i| pool[i].clone()))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<T>
+    {
+        if self.done {
+            return None
+        }
+        let k = self.indices.len();
+        // the position of the combination to skip to, counting the current
+        // one (or, before the first call, the first one) as position 0
+        let mut skip = n;
+        if self.first {
+            self.first = false;
+            self.pool.extend(self.iter.by_ref());
+            if k > self.pool.len() {
+                self.done = true;
+                return None
+            }
+        } else {
+            skip = match skip.checked_add(1) {
+                Some(x) => x,
+                None => {
+                    self.done = true;
+                    return None
+                }
+            };
+        }
+        // Jump straight to the target by unranking it; if the counts
+        // overflow, step there one combination at a time instead.
+        let len = self.pool.len();
+        let total = checked_binomial(len, k);
+        let rest = checked_remaining(len, &self.indices);
+        if let (Some(total), Some(rest)) = (total, rest) {
+            let rank = total - 1 - rest;
+            if skip > rest {
+                self.done = true;
+                return None
+            }
+            if let Some(indices) = checked_unrank(len, k, rank + skip) {
+                self.indices = indices;
+                let pool = &self.pool;
+                return T::collect_from_iter(self.indices.iter().map(|&i| pool[i].clone()))
+            }
+        }
+        // the current combination counts as one step, except before the first call
+        if skip == 0 {
+            let pool = &self.pool;
+            return T::collect_from_iter(self.indices.iter().map(|&i| pool[i].clone()))
+        }
+        for _ in 1..skip {
+            if self.next().is_none() {
+                return None
+            }
+        }
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let k = self.indices.len();
+        if self.done {
+            (0, Some(0))
+        } else if self.first {
+            let (low, hi) = self.iter.size_hint();
+            (checked_binomial(low, k).unwrap_or(::std::usize::MAX),
+             hi.and_then(|hi| checked_binomial(hi, k)))
+        } else {
+            let count = checked_remaining(self.pool.len(), &self.indices);
+            (count.unwrap_or(::std::usize::MAX), count)
+        }
+    }
+}
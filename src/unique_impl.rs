@@ -0,0 +1,102 @@
+use std::borrow::ToOwned;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+
+/// An iterator adaptor that filters out elements whose key has already been
+/// seen, where the key is borrowed from the element.
+///
+/// The owned form of a key is only created the first time the key is seen;
+/// duplicates are looked up through the borrowed form and never cloned.
+///
+/// See [*.unique_by_owned()*](trait.Itertools.html#method.unique_by_owned)
+/// for more information.
+///
+/// Iterator element type is **I::Item**.
+pub struct UniqueByOwned<I, F, K: ?Sized> where
+    K: ToOwned,
+{
+    iter: I,
+    f: F,
+    used: HashSet<K::Owned>,
+}
+
+impl<I, F, K: ?Sized> UniqueByOwned<I, F, K> where
+    K: ToOwned,
+    K::Owned: Hash + Eq,
+{
+    /// Create a new **UniqueByOwned** from an iterator and a key function.
+    pub fn new(iter: I, f: F) -> Self
+    {
+        UniqueByOwned {
+            iter: iter,
+            f: f,
+            used: HashSet::new(),
+        }
+    }
+}
+
+impl<I, F, K: ?Sized> Clone for UniqueByOwned<I, F, K> where
+    I: Clone,
+    F: Clone,
+    K: ToOwned,
+    K::Owned: Clone,
+{
+    fn clone(&self) -> Self
+    {
+        UniqueByOwned {
+            iter: self.iter.clone(),
+            f: self.f.clone(),
+            used: self.used.clone(),
+        }
+    }
+}
+
+impl<I, F, K: ?Sized> fmt::Debug for UniqueByOwned<I, F, K> where
+    I: fmt::Debug,
+    K: ToOwned,
+    K::Owned: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result
+    {
+        f.debug_struct("UniqueByOwned")
+            .field("iter", &self.iter)
+            .field("used", &self.used)
+            .finish()
+    }
+}
+
+impl<I, F, K: ?Sized> Iterator for UniqueByOwned<I, F, K> where
+    I: Iterator,
+    F: for<'a> FnMut(&'a I::Item) -> &'a K,
+    K: ToOwned + Hash + Eq,
+    K::Owned: Hash + Eq,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item>
+    {
+        while let Some(elt) = self.iter.next() {
+            let is_new = {
+                let key = (self.f)(&elt);
+                if self.used.contains(key) {
+                    false
+                } else {
+                    self.used.insert(key.to_owned());
+                    true
+                }
+            };
+            if is_new {
+                return Some(elt);
+            }
+        }
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        // the first element is always new
+        let (low, hi) = self.iter.size_hint();
+        ((low > 0 && self.used.is_empty()) as usize, hi)
+    }
+}
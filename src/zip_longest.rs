@@ -1,10 +1,10 @@
-use std::cmp::Ordering::{Equal, Greater, Less};
+use core::cmp::Ordering::{Equal, Greater, Less};
 #[cfg(feature = "unstable")]
-use std::cmp;
+use core::cmp;
 #[cfg(feature = "unstable")]
-use std::iter::RandomAccessIterator;
+use core::iter::RandomAccessIterator;
 use super::size_hint;
-use std::iter::Fuse;
+use core::iter::Fuse;
 use self::EitherOrBoth::{Right, Left, Both};
 
 // ZipLongest originally written by SimonSapin,
@@ -99,6 +99,62 @@ impl<T, U> ExactSizeIterator for ZipLongest<T, U> where
     U: ExactSizeIterator,
 {}
 
+/// An iterator which iterates two other iterators simultaneously, padding
+/// the shorter one with values from fill closures.
+///
+/// See [*.zip_pad()*](trait.Itertools.html#method.zip_pad) for more information.
+///
+/// This iterator is *fused*.
+#[derive(Clone)]
+#[must_use = "iterator adaptors are lazy and do nothing unless consumed"]
+pub struct ZipPad<T, U, FA, FB> {
+    iter: ZipLongest<T, U>,
+    fill_left: FA,
+    fill_right: FB,
+}
+
+impl<T, U, FA, FB> ZipPad<T, U, FA, FB> where
+    T: Iterator,
+    U: Iterator,
+{
+    /// Create a new **ZipPad** iterator.
+    pub fn new(a: T, b: U, fill_left: FA, fill_right: FB) -> Self
+    {
+        ZipPad{iter: ZipLongest::new(a, b), fill_left: fill_left, fill_right: fill_right}
+    }
+}
+
+impl<T, U, FA, FB> Iterator for ZipPad<T, U, FA, FB> where
+    T: Iterator,
+    U: Iterator,
+    FA: FnMut() -> T::Item,
+    FB: FnMut() -> U::Item,
+{
+    type Item = (T::Item, U::Item);
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        match self.iter.next() {
+            None => None,
+            Some(Both(a, b)) => Some((a, b)),
+            Some(Left(a)) => Some((a, (self.fill_right)())),
+            Some(Right(b)) => Some(((self.fill_left)(), b)),
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<T, U, FA, FB> ExactSizeIterator for ZipPad<T, U, FA, FB> where
+    T: ExactSizeIterator,
+    U: ExactSizeIterator,
+    FA: FnMut() -> T::Item,
+    FB: FnMut() -> U::Item,
+{}
+
 
 /// A value yielded by `ZipLongest`.
 /// Contains one or two values,
@@ -114,3 +170,404 @@ pub enum EitherOrBoth<A, B> {
     /// only yielding a value from the parameter iterator.
     Right(B),
 }
+
+impl<A, B> EitherOrBoth<A, B> {
+    /// If **Left** or **Both**, return **true**, otherwise **false**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left, Right};
+    ///
+    /// assert!(Left::<_, ()>(1).has_left());
+    /// assert!(Both(1, 2).has_left());
+    /// assert!(!Right::<(), _>(2).has_left());
+    /// ```
+    pub fn has_left(&self) -> bool
+    {
+        self.as_ref().left().is_some()
+    }
+
+    /// If **Right** or **Both**, return **true**, otherwise **false**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left, Right};
+    ///
+    /// assert!(Right::<(), _>(2).has_right());
+    /// assert!(Both(1, 2).has_right());
+    /// assert!(!Left::<_, ()>(1).has_right());
+    /// ```
+    pub fn has_right(&self) -> bool
+    {
+        self.as_ref().right().is_some()
+    }
+
+    /// If **Both**, return **true**, otherwise **false**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left};
+    ///
+    /// assert!(Both(1, 2).is_both());
+    /// assert!(!Left::<_, ()>(1).is_both());
+    /// ```
+    pub fn is_both(&self) -> bool
+    {
+        self.as_ref().both().is_some()
+    }
+
+    /// If **Left** or **Both**, return **Some** with the left value,
+    /// otherwise **None**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left, Right};
+    ///
+    /// assert_eq!(Left::<_, ()>(1).left(), Some(1));
+    /// assert_eq!(Both(1, 2).left(), Some(1));
+    /// assert_eq!(Right::<(), _>(2).left(), None);
+    /// ```
+    pub fn left(self) -> Option<A>
+    {
+        match self {
+            Left(left) | Both(left, _) => Some(left),
+            _ => None,
+        }
+    }
+
+    /// If **Right** or **Both**, return **Some** with the right value,
+    /// otherwise **None**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left, Right};
+    ///
+    /// assert_eq!(Right::<(), _>(2).right(), Some(2));
+    /// assert_eq!(Both(1, 2).right(), Some(2));
+    /// assert_eq!(Left::<_, ()>(1).right(), None);
+    /// ```
+    pub fn right(self) -> Option<B>
+    {
+        match self {
+            Right(right) | Both(_, right) => Some(right),
+            _ => None,
+        }
+    }
+
+    /// If **Both**, return **Some** tuple containing left and right,
+    /// otherwise **None**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left};
+    ///
+    /// assert_eq!(Both(1, 2).both(), Some((1, 2)));
+    /// assert_eq!(Left::<_, ()>(1).both(), None);
+    /// ```
+    pub fn both(self) -> Option<(A, B)>
+    {
+        match self {
+            Both(a, b) => Some((a, b)),
+            _ => None,
+        }
+    }
+
+    /// If **Left**, return **Some** with the left value, otherwise **None**.
+    ///
+    /// Unlike *.left()*, this returns **None** for **Both**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left};
+    ///
+    /// assert_eq!(Left::<_, ()>(1).just_left(), Some(1));
+    /// assert_eq!(Both(1, 2).just_left(), None);
+    /// ```
+    pub fn just_left(self) -> Option<A>
+    {
+        match self {
+            Left(left) => Some(left),
+            _ => None,
+        }
+    }
+
+    /// If **Right**, return **Some** with the right value, otherwise **None**.
+    ///
+    /// Unlike *.right()*, this returns **None** for **Both**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Right};
+    ///
+    /// assert_eq!(Right::<(), _>(2).just_right(), Some(2));
+    /// assert_eq!(Both(1, 2).just_right(), None);
+    /// ```
+    pub fn just_right(self) -> Option<B>
+    {
+        match self {
+            Right(right) => Some(right),
+            _ => None,
+        }
+    }
+
+    /// Convert from **&EitherOrBoth\<A, B\>** to **EitherOrBoth\<&A, &B\>**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left};
+    ///
+    /// let x = Both(String::from("a"), 2);
+    /// assert_eq!(x.as_ref().map_left(|s| s.len()), Both(1, &2));
+    /// assert_eq!(Left::<_, ()>(1).as_ref(), Left(&1));
+    /// ```
+    pub fn as_ref(&self) -> EitherOrBoth<&A, &B>
+    {
+        match *self {
+            Left(ref left) => Left(left),
+            Right(ref right) => Right(right),
+            Both(ref left, ref right) => Both(left, right),
+        }
+    }
+
+    /// Convert from **&mut EitherOrBoth\<A, B\>** to **EitherOrBoth\<&mut A, &mut B\>**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Right};
+    ///
+    /// let mut x = Both(1, 2);
+    /// if let Both(a, b) = x.as_mut() {
+    ///     *a += 10;
+    ///     *b *= 10;
+    /// }
+    /// assert_eq!(x, Both(11, 20));
+    ///
+    /// let mut y = Right::<(), _>(2);
+    /// if let Some(b) = y.as_mut().right() {
+    ///     *b = 3;
+    /// }
+    /// assert_eq!(y, Right(3));
+    /// ```
+    pub fn as_mut(&mut self) -> EitherOrBoth<&mut A, &mut B>
+    {
+        match *self {
+            Left(ref mut left) => Left(left),
+            Right(ref mut right) => Right(right),
+            Both(ref mut left, ref mut right) => Both(left, right),
+        }
+    }
+
+    /// Apply the function **f** on the value in the left position if it is
+    /// present.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Right};
+    ///
+    /// assert_eq!(Both(1, 2).map_left(|x| x * 10), Both(10, 2));
+    /// assert_eq!(Right::<i32, _>(2).map_left(|x| x * 10), Right(2));
+    /// ```
+    pub fn map_left<F, M>(self, f: F) -> EitherOrBoth<M, B> where
+        F: FnOnce(A) -> M,
+    {
+        match self {
+            Both(a, b) => Both(f(a), b),
+            Left(a) => Left(f(a)),
+            Right(b) => Right(b),
+        }
+    }
+
+    /// Apply the function **f** on the value in the right position if it is
+    /// present.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left};
+    ///
+    /// assert_eq!(Both(1, 2).map_right(|x| x * 10), Both(1, 20));
+    /// assert_eq!(Left::<_, i32>(1).map_right(|x| x * 10), Left(1));
+    /// ```
+    pub fn map_right<F, M>(self, f: F) -> EitherOrBoth<A, M> where
+        F: FnOnce(B) -> M,
+    {
+        match self {
+            Left(a) => Left(a),
+            Right(b) => Right(f(b)),
+            Both(a, b) => Both(a, f(b)),
+        }
+    }
+
+    /// Apply the functions **f** and **g** on the values in the left and
+    /// right positions respectively, where they are present.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left};
+    ///
+    /// assert_eq!(Both(1, "ab").map_any(|x| x + 1, |s| s.len()), Both(2, 2));
+    /// assert_eq!(Left::<_, &str>(1).map_any(|x| x + 1, |s| s.len()), Left(2));
+    /// ```
+    pub fn map_any<F, L, G, R>(self, f: F, g: G) -> EitherOrBoth<L, R> where
+        F: FnOnce(A) -> L,
+        G: FnOnce(B) -> R,
+    {
+        match self {
+            Left(a) => Left(f(a)),
+            Right(b) => Right(g(b)),
+            Both(a, b) => Both(f(a), g(b)),
+        }
+    }
+
+    /// Return the left value if present, otherwise **default**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Right};
+    ///
+    /// assert_eq!(Both(1, 2).left_or(0), 1);
+    /// assert_eq!(Right(2).left_or(0), 0);
+    /// ```
+    pub fn left_or(self, default: A) -> A
+    {
+        self.left().unwrap_or(default)
+    }
+
+    /// Return the right value if present, otherwise **default**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left};
+    ///
+    /// assert_eq!(Both(1, 2).right_or(0), 2);
+    /// assert_eq!(Left(1).right_or(0), 0);
+    /// ```
+    pub fn right_or(self, default: B) -> B
+    {
+        self.right().unwrap_or(default)
+    }
+
+    /// Return a tuple of both values, filling in the missing side with
+    /// **l** or **r** respectively.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left, Right};
+    ///
+    /// assert_eq!(Both(1, 'b').or(0, 'x'), (1, 'b'));
+    /// assert_eq!(Left(1).or(0, 'x'), (1, 'x'));
+    /// assert_eq!(Right('b').or(0, 'x'), (0, 'b'));
+    /// ```
+    pub fn or(self, l: A, r: B) -> (A, B)
+    {
+        match self {
+            Left(a) => (a, r),
+            Right(b) => (l, b),
+            Both(a, b) => (a, b),
+        }
+    }
+
+    /// Return a tuple of both values, filling in the missing side with
+    /// its **Default** value.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Left, Right};
+    ///
+    /// assert_eq!(Left::<_, String>(1).or_default(), (1, String::new()));
+    /// assert_eq!(Right::<i32, _>('b').or_default(), (0, 'b'));
+    /// ```
+    pub fn or_default(self) -> (A, B) where
+        A: Default,
+        B: Default,
+    {
+        match self {
+            Left(a) => (a, B::default()),
+            Right(b) => (A::default(), b),
+            Both(a, b) => (a, b),
+        }
+    }
+
+    /// Return the left value, converting the right value with **Into**
+    /// if only that is present.
+    ///
+    /// For **Both**, the right value is dropped and the left value is returned.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left, Right};
+    ///
+    /// assert_eq!(Left::<u32, u8>(1).into_left(), 1);
+    /// assert_eq!(Right::<u32, u8>(2).into_left(), 2);
+    /// assert_eq!(Both::<u32, u8>(1, 2).into_left(), 1);
+    /// ```
+    pub fn into_left(self) -> A where
+        B: Into<A>,
+    {
+        match self {
+            Left(a) | Both(a, _) => a,
+            Right(b) => b.into(),
+        }
+    }
+
+    /// Return the right value, converting the left value with **Into**
+    /// if only that is present.
+    ///
+    /// For **Both**, the left value is dropped and the right value is returned.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left, Right};
+    ///
+    /// assert_eq!(Left::<u8, u32>(1).into_right(), 1);
+    /// assert_eq!(Right::<u8, u32>(2).into_right(), 2);
+    /// assert_eq!(Both::<u8, u32>(1, 2).into_right(), 2);
+    /// ```
+    pub fn into_right(self) -> B where
+        A: Into<B>,
+    {
+        match self {
+            Right(b) | Both(_, b) => b,
+            Left(a) => a.into(),
+        }
+    }
+}
+
+impl<T> EitherOrBoth<T, T> {
+    /// Return either value of the homogeneous **EitherOrBoth**, combining
+    /// them with **f** for **Both**.
+    ///
+    /// ```
+    /// use itertools::EitherOrBoth::{Both, Left, Right};
+    ///
+    /// assert_eq!(Left(1).reduce(|a, b| a + b), 1);
+    /// assert_eq!(Right(2).reduce(|a, b| a + b), 2);
+    /// assert_eq!(Both(1, 2).reduce(|a, b| a + b), 3);
+    /// ```
+    pub fn reduce<F>(self, f: F) -> T where
+        F: FnOnce(T, T) -> T,
+    {
+        match self {
+            Left(a) => a,
+            Right(b) => b,
+            Both(a, b) => f(a, b),
+        }
+    }
+}
+
+/// Convert into a pair of optional values, which never is **(None, None)**.
+///
+/// ```
+/// use itertools::EitherOrBoth::{Both, Left};
+///
+/// assert_eq!(<(Option<_>, Option<_>)>::from(Both(1, 'b')), (Some(1), Some('b')));
+/// assert_eq!(<(Option<_>, Option<char>)>::from(Left(1)), (Some(1), None));
+/// ```
+impl<A, B> From<EitherOrBoth<A, B>> for (Option<A>, Option<B>) {
+    fn from(value: EitherOrBoth<A, B>) -> Self
+    {
+        match value {
+            Left(a) => (Some(a), None),
+            Right(b) => (None, Some(b)),
+            Both(a, b) => (Some(a), Some(b)),
+        }
+    }
+}
+
+/// Convert **Ok** into **Left** and **Err** into **Right**.
+///
+/// ```
+/// use itertools::EitherOrBoth::{self, Left, Right};
+///
+/// assert_eq!(EitherOrBoth::from(Ok::<_, ()>(1)), Left(1));
+/// assert_eq!(EitherOrBoth::from(Err::<(), _>(2)), Right(2));
+/// ```
+impl<A, B> From<Result<A, B>> for EitherOrBoth<A, B> {
+    fn from(result: Result<A, B>) -> Self
+    {
+        match result {
+            Ok(a) => Left(a),
+            Err(b) => Right(b),
+        }
+    }
+}
@@ -1,7 +1,8 @@
+#[cfg(feature = "use_std")]
 use std::vec;
-use std::slice;
-use std::iter;
-use std::cmp;
+use core::slice;
+use core::iter;
+use core::cmp;
 
 
 /// A **TrustedIterator** has exact size, always.
@@ -12,15 +13,16 @@ pub unsafe trait TrustedIterator : ExactSizeIterator
     /* no methods */
 }
 
-unsafe impl TrustedIterator for ::std::ops::Range<usize> { }
-unsafe impl TrustedIterator for ::std::ops::Range<u32> { }
-unsafe impl TrustedIterator for ::std::ops::Range<i32> { }
-unsafe impl TrustedIterator for ::std::ops::Range<u16> { }
-unsafe impl TrustedIterator for ::std::ops::Range<i16> { }
-unsafe impl TrustedIterator for ::std::ops::Range<u8> { }
-unsafe impl TrustedIterator for ::std::ops::Range<i8> { }
+unsafe impl TrustedIterator for ::core::ops::Range<usize> { }
+unsafe impl TrustedIterator for ::core::ops::Range<u32> { }
+unsafe impl TrustedIterator for ::core::ops::Range<i32> { }
+unsafe impl TrustedIterator for ::core::ops::Range<u16> { }
+unsafe impl TrustedIterator for ::core::ops::Range<i16> { }
+unsafe impl TrustedIterator for ::core::ops::Range<u8> { }
+unsafe impl TrustedIterator for ::core::ops::Range<i8> { }
 unsafe impl<'a, T> TrustedIterator for slice::Iter<'a, T> { }
 unsafe impl<'a, T> TrustedIterator for slice::IterMut<'a, T> { }
+#[cfg(feature = "use_std")]
 unsafe impl<T> TrustedIterator for vec::IntoIter<T> { }
 
 unsafe impl<I> TrustedIterator for iter::Rev<I> where
@@ -95,7 +97,7 @@ macro_rules! impl_zip_trusted {
             #[inline]
             fn set_length(&mut self)
             {
-                let len = ::std::usize::MAX;
+                let len = ::core::usize::MAX;
                 let ($(ref $B,)*) = self.t;
                 $(
                     let (l, h) = $B.size_hint();
@@ -126,7 +128,7 @@ macro_rules! impl_zip_trusted {
                     let next_opt = $B.next();
                     let $B;
                     unsafe {
-                        ::std::intrinsics::assume(match next_opt {
+                        ::core::intrinsics::assume(match next_opt {
                             None => false,
                             Some(_) => true,
                         });
@@ -11,6 +11,8 @@ use super::size_hint;
 /// The iterator element type is a tuple like like **(A, B, ..., E)** where **A** to **E** are the
 /// element types of the subiterator.
 ///
+/// **Zip** is implemented for tuples of up to twelve iterators.
+///
 /// ## Example
 ///
 /// ```
@@ -82,7 +84,7 @@ macro_rules! impl_zip_iter {
 
             fn size_hint(&self) -> (usize, Option<usize>)
             {
-                let sh = (::std::usize::MAX, None);
+                let sh = (::core::usize::MAX, None);
                 let ($(ref $B,)*) = self.t;
                 $(
                     let sh = size_hint::min($B.size_hint(), sh);
@@ -109,4 +111,6 @@ impl_zip_iter!(A, B, C, D, E, F);
 impl_zip_iter!(A, B, C, D, E, F, G);
 impl_zip_iter!(A, B, C, D, E, F, G, H);
 impl_zip_iter!(A, B, C, D, E, F, G, H, I);
-
+impl_zip_iter!(A, B, C, D, E, F, G, H, I, J);
+impl_zip_iter!(A, B, C, D, E, F, G, H, I, J, K);
+impl_zip_iter!(A, B, C, D, E, F, G, H, I, J, K, L);
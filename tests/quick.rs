@@ -191,6 +191,19 @@ fn size_product(a: Iter<u16>, b: Iter<u16>) -> bool {
     correct_size_hint(a.cartesian_product(b))
 }
 
+#[quickcheck]
+fn product_fold_count_last(a: Vec<u8>, b: Vec<u8>, consumed: usize) -> bool {
+    let mut prod = a.iter().cartesian_product(b.iter());
+    for _ in 0..consumed % 32 {
+        prod.next();
+    }
+    let expected = prod.clone().collect_vec();
+    let folded = prod.clone().fold(Vec::new(), |mut acc, elt| { acc.push(elt); acc });
+    folded == expected &&
+        prod.clone().count() == expected.len() &&
+        prod.last() == expected.last().cloned()
+}
+
 #[quickcheck]
 fn size_product3(a: Iter<u16>, b: Iter<u16>, c: Iter<u16>) -> bool {
     correct_size_hint(iproduct!(a, b, c))
@@ -255,6 +268,32 @@ fn size_zip_longest(a: Iter<i16>, b: Iter<i16>) -> bool {
         exact_size(a.zip_longest(b))
 }
 
+#[quickcheck]
+fn size_put_back(a: Vec<u8>, x: Option<u8>) -> bool {
+    let mut it = itertools::PutBack::new(a.into_iter());
+    match x {
+        Some(t) => it.put_back(t),
+        None => {}
+    }
+    correct_size_hint(it.clone()) && exact_size(it)
+}
+
+#[quickcheck]
+fn size_put_back_n(a: Vec<u8>, b: Vec<u8>) -> bool {
+    let mut it = itertools::put_back_n(a.into_iter());
+    for elt in b {
+        it.put_back(elt)
+    }
+    correct_size_hint(it.clone()) && exact_size(it)
+}
+
+#[quickcheck]
+fn size_zip_pad(a: Iter<i16>, b: Iter<i16>) -> bool {
+    let filt = a.clone().dedup();
+    correct_size_hint(filt.zip_pad(b.clone(), || 0, || 0)) &&
+        exact_size(a.zip_pad(b, || 0, || 0))
+}
+
 #[quickcheck]
 fn size_2_zip_longest(a: Iter<i16>, b: Iter<i16>) -> bool {
     let it = a.clone().zip_longest(b.clone());
@@ -325,6 +364,62 @@ fn size_dedup(a: Vec<i32>) -> bool {
     correct_size_hint(a.iter().dedup())
 }
 
+#[quickcheck]
+fn equal_dedup_by_key(a: Vec<(u8, i32)>) -> bool {
+    let mut b = a.clone();
+    b.dedup_by(|x, y| x.0 == y.0);
+    itertools::equal(&b, a.iter().dedup_by_key(|x| x.0))
+}
+
+#[quickcheck]
+fn coalesce_fold(a: Vec<u8>) -> bool {
+    // merge while the running sum stays below a threshold
+    let merge = |x: u32, y: u32| if x + y < 300 { Ok(x + y) } else { Err((x, y)) };
+    let input = a.iter().map(|&x| x as u32).collect_vec();
+    let lazy = input.iter().cloned().coalesce(merge).collect_vec();
+    let folded = input.iter().cloned().coalesce(merge)
+                      .fold(Vec::new(), |mut v, x| { v.push(x); v });
+    lazy == folded
+}
+
+#[quickcheck]
+fn dedup_fold(a: Vec<u8>) -> bool {
+    let lazy = a.iter().dedup().collect_vec();
+    let folded = a.iter().dedup().fold(Vec::new(), |mut v, x| { v.push(x); v });
+    let lazy_by_key = a.iter().dedup_by_key(|&&x| x / 4).collect_vec();
+    let folded_by_key = a.iter().dedup_by_key(|&&x| x / 4)
+                         .fold(Vec::new(), |mut v, x| { v.push(x); v });
+    lazy == folded && lazy_by_key == folded_by_key
+}
+
+#[quickcheck]
+fn positions_double_ended(a: Vec<u8>, pattern: Vec<bool>) -> bool {
+    let expected = a.iter().enumerate()
+                    .filter(|&(_, &v)| v % 3 == 0)
+                    .map(|(i, _)| i)
+                    .collect::<Vec<_>>();
+    let mut it = a.iter().positions(|&v| v % 3 == 0);
+    let (mut front, mut back) = (0, expected.len());
+    for from_back in pattern {
+        let (got, want) = if from_back {
+            let want = if back > front { back -= 1; Some(expected[back]) } else { None };
+            (it.next_back(), want)
+        } else {
+            let want = if front < back { front += 1; Some(expected[front - 1]) } else { None };
+            (it.next(), want)
+        };
+        if got != want {
+            return false;
+        }
+    }
+    it.collect::<Vec<_>>() == &expected[front..back]
+}
+
+#[quickcheck]
+fn size_dedup_by_key(a: Vec<(u8, i32)>) -> bool {
+    correct_size_hint(a.iter().dedup_by_key(|x| x.0))
+}
+
 #[quickcheck]
 fn size_group_by(a: Vec<i8>) -> bool {
     correct_size_hint(a.iter().group_by(|x| x.abs()))
@@ -414,6 +509,77 @@ fn size_take_while_ref(a: Vec<u8>, stop: u8) -> bool {
     correct_size_hint(a.iter().take_while_ref(|x| **x != stop))
 }
 
+#[quickcheck]
+fn size_take_while_inclusive(a: Iter<i16>, stop: i16) -> bool {
+    correct_size_hint(a.take_while_inclusive(|x| *x != stop))
+}
+
+#[quickcheck]
+fn size_combinations(a: Iter<i16>) -> bool {
+    correct_size_hint(a.combinations())
+}
+
+#[quickcheck]
+fn size_tuple_combinations(a: Vec<u8>) -> bool {
+    let a = &a[..a.len() % 20];
+    correct_size_hint(a.iter().tuple_combinations::<(_, _)>()) &&
+        correct_size_hint(a.iter().tuple_combinations::<(_, _, _)>())
+}
+
+#[quickcheck]
+fn equal_windows(a: Vec<u8>, n: u8) -> bool {
+    let n = n as usize % 8 + 1;
+    correct_size_hint(a.iter().windows(n)) &&
+        itertools::equal(a.windows(n).map(|w| w.iter().collect_vec()),
+                         a.iter().windows(n))
+}
+
+#[quickcheck]
+fn equal_split_when(a: Vec<u8>) -> bool {
+    let segments = a.iter().split_when(|x, y| x > y).collect_vec();
+    correct_size_hint(a.iter().split_when(|x, y| x > y)) &&
+        segments.iter().all(|s| !s.is_empty()) &&
+        itertools::equal(a.iter(), segments.into_iter().flat_map(|s| s))
+}
+
+#[quickcheck]
+fn size_split_on(a: Vec<u8>) -> bool {
+    correct_size_hint(a.iter().split_on(|&&x| x % 4 == 0))
+}
+
+#[quickcheck]
+fn size_interleave_all(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>) -> bool {
+    correct_size_hint(vec![a, b, c].into_iter().interleave_all())
+}
+
+#[quickcheck]
+fn equal_kmerge(a: Vec<i16>, b: Vec<i16>, c: Vec<i16>) -> bool {
+    let mut sa = a.clone();
+    let mut sb = b.clone();
+    let mut sc = c.clone();
+    sa.sort();
+    sb.sort();
+    sc.sort();
+    let mut merged = sa.clone();
+    merged.extend(sb.iter().cloned());
+    merged.extend(sc.iter().cloned());
+    merged.sort();
+    itertools::equal(merged.into_iter(), vec![sa, sb, sc].into_iter().kmerge())
+}
+
+#[quickcheck]
+fn equal_kmerge_many(runs: Vec<Vec<u8>>) -> bool {
+    let runs = runs.into_iter().map(|mut v| { v.sort(); v }).collect::<Vec<_>>();
+    let mut merged = runs.iter().flat_map(|v| v.iter().cloned()).collect::<Vec<_>>();
+    merged.sort();
+    itertools::equal(merged.into_iter(), runs.into_iter().kmerge())
+}
+
+#[quickcheck]
+fn size_kmerge(a: Iter<i16>, b: Iter<i16>, c: Iter<i16>) -> bool {
+    correct_size_hint(vec![a, b, c].into_iter().kmerge())
+}
+
 #[quickcheck]
 fn equal_partition(mut a: Vec<i32>) -> bool {
     let mut ap = a.clone();
@@ -0,0 +1,167 @@
+//! Licensed under the Apache License, Version 2.0
+//! http://www.apache.org/licenses/LICENSE-2.0 or the MIT license
+//! http://opensource.org/licenses/MIT, at your
+//! option. This file may not be copied, modified, or distributed
+//! except according to those terms.
+
+//! Tests for the core-only surface of itertools: everything here must
+//! keep working with `--no-default-features`.
+
+#![no_std]
+
+#[macro_use]
+extern crate itertools as it;
+
+use it::Itertools;
+use it::EitherOrBoth::{Both, Left};
+use it::MinMaxResult;
+use it::{assert_equal, PutBack};
+
+#[test]
+fn product3() {
+    let prod = iproduct!(0..3, 0..2, 0..2);
+    assert_eq!(prod.size_hint(), (12, Some(12)));
+    let mut count = 0;
+    for (i, (a, b, c)) in prod.enumerate() {
+        assert_eq!(i, a * 4 + b * 2 + c);
+        count += 1;
+    }
+    assert_eq!(count, 12);
+}
+
+#[test]
+fn izip_macro() {
+    let xs = [0, 1, 2];
+    let ys = [3, 4, 5, 6];
+    assert_equal(izip!(&xs, &ys, 0..), [(&0, &3, 0), (&1, &4, 1), (&2, &5, 2)].iter().cloned());
+}
+
+#[test]
+fn chain_macro() {
+    assert_equal(chain!(0..2, Some(2), [3, 4].iter().cloned()), [0, 1, 2, 3, 4].iter().cloned());
+}
+
+#[test]
+fn interleave_intersperse() {
+    assert_equal((0..3).interleave(10..12), [0, 10, 1, 11, 2].iter().cloned());
+    assert_equal((0..3).intersperse(-1), [0, -1, 1, -1, 2].iter().cloned());
+}
+
+#[test]
+fn dedup_coalesce() {
+    let xs = [0, 1, 1, 1, 2, 1, 3, 3];
+    assert_equal(xs.iter().dedup(), [0, 1, 2, 1, 3].iter());
+    assert_equal(xs.iter().dedup().rev(), [3, 1, 2, 1, 0].iter());
+    assert_equal(xs.iter().dedup_by_key(|&&x| x / 2), [0, 2, 1, 3].iter());
+    let sums = xs.iter().cloned().coalesce(|x, y| if x == y { Ok(x + y) } else { Err((x, y)) });
+    assert_equal(sums, [0, 2, 1, 2, 1, 6].iter().cloned());
+}
+
+#[test]
+fn zip_longest() {
+    let mut it = (0..2).zip_longest(0..1);
+    assert_eq!(it.next(), Some(Both(0, 0)));
+    assert_eq!(it.next(), Some(Left(1)));
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn adaptors() {
+    assert_equal((0..10).step(3), [0, 3, 6, 9].iter().cloned());
+    assert_equal((0..10).step(3).rev(), [9, 6, 3, 0].iter().cloned());
+    assert_equal((0..4).merge(2..6), [0, 1, 2, 2, 3, 3, 4, 5].iter().cloned());
+    assert_equal((0..10).positions(|x| x % 4 == 0), [0, 4, 8].iter().cloned());
+    assert_equal((1..10).take_while_inclusive(|&x| x < 3), [1, 2, 3].iter().cloned());
+    assert_equal((0..3).cartesian_product(0..2).filter(|&(a, b)| a == b),
+                 [(0, 0), (1, 1)].iter().cloned());
+    assert_equal((0..10).dropping(7), 7..10);
+    assert_equal((5..7).enumerate_from(1).rev(), [(2, 6), (1, 5)].iter().cloned());
+    assert_equal((0..2).pad_using(4, |i| i * 2).rev(), [6, 4, 1, 0].iter().cloned());
+    assert_equal((0..3).pairwise(), [(0, 1), (1, 2)].iter().cloned());
+    assert_equal((1..5).scan1(|acc, x| acc + x), [1, 3, 6, 10].iter().cloned());
+
+    let results = [Ok(2), Err('x'), Ok(0), Err('y'), Ok(3)];
+    let it = results.iter().cloned().flat_map_ok(|n| 0..n);
+    assert_equal(it, [Ok(0), Ok(1), Err('x'), Err('y'), Ok(0), Ok(1), Ok(2)].iter().cloned());
+}
+
+#[test]
+fn peeking_take_while() {
+    let mut it = PutBack::new(0..10);
+    assert_eq!(it.peeking_take_while(|&x| x < 3).count(), 3);
+    assert_eq!(it.next(), Some(3));
+}
+
+#[test]
+fn sources() {
+    assert_equal(it::repeat_n(1, 3), [1, 1, 1].iter().cloned());
+    assert_equal(it::iterate(1, |&x| x * 2).take(4), [1, 2, 4, 8].iter().cloned());
+    let mut n = 0;
+    assert_equal(it::repeat_call(|| { n += 1; n }).take(3), 1..4);
+    assert_equal(it::tabulate(|i| i * i).take(4), [0, 1, 4, 9].iter().cloned());
+    assert_equal(it::unfold(3, |n| if *n == 0 { None } else { *n -= 1; Some(*n) }),
+                 [2, 1, 0].iter().cloned());
+}
+
+#[test]
+fn consumers() {
+    assert_eq!((1..5).fold1(|a, b| a * b), Some(24));
+    assert_eq!((1..5).product1::<i32>(), Some(24));
+    assert_eq!((1..10).fold_while(0, |acc, x| if x > 3 { it::FoldWhile::Done(acc) }
+                                             else { it::FoldWhile::Continue(acc + x) })
+                      .into_inner(), 6);
+    assert_eq!([1, 2, 3].iter().sum1::<i32>(), Some(6));
+    assert_eq!([0, 0].iter().sum1::<i32>(), Some(0));
+    assert_eq!((0..0).sum1::<i32>(), None);
+    assert_eq!((0..0).product1::<i32>(), None);
+    assert_eq!([200u8, 56].iter().checked_sum::<u8>(), None);
+    assert_eq!((0..7).minmax(), MinMaxResult::MinMax(0, 6));
+    assert_eq!((0..1).exactly_one().ok(), Some(0));
+    assert!((0..2).at_most_one().is_err());
+    assert!([1, 1, 1].iter().all_equal());
+    assert!(it::equal(0..3, [0, 1, 2].iter().cloned()));
+    assert_eq!((1..5).find_or_last(|&x| x > 10), Some(4));
+    assert_eq!((1..5).find_or_first(|&x| x > 10), Some(1));
+
+    let results = [Ok(1), Err(()), Ok(2)];
+    let (oks, errs): (Sum, Count) = results.iter().cloned().partition_result();
+    assert_eq!((oks.0, errs.0), (3, 1));
+    assert_eq!(results.iter().cloned().process_results(|it| it.sum::<i32>()), Err(()));
+
+    let mut xs = [0; 4];
+    assert_eq!(xs.iter_mut().set_from(1..), 4);
+    assert_eq!(xs, [1, 2, 3, 4]);
+}
+
+#[derive(Default)]
+struct Sum(i32);
+
+impl Extend<i32> for Sum {
+    fn extend<T: IntoIterator<Item=i32>>(&mut self, iter: T) {
+        for x in iter { self.0 += x; }
+    }
+}
+
+#[derive(Default)]
+struct Count(usize);
+
+impl<A> Extend<A> for Count {
+    fn extend<T: IntoIterator<Item=A>>(&mut self, iter: T) {
+        self.0 += iter.into_iter().count();
+    }
+}
+
+#[test]
+fn cloned_copied() {
+    let mut it = it::cloned(&[1, 2, 3]).rev();
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next(), Some(3));
+    assert_equal(it, [2, 1].iter().cloned());
+
+    let xs = [1, 2, 3];
+    let mut it = it::copied(xs.iter().skip(1));
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.next_back(), Some(3));
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.next(), None);
+}
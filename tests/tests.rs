@@ -4,6 +4,8 @@
 //! option. This file may not be copied, modified, or distributed
 //! except according to those terms.
 
+#![cfg(feature = "use_std")]
+
 #[macro_use]
 extern crate itertools as it;
 
@@ -54,6 +56,27 @@ fn product3() {
     }
 }
 
+#[test]
+fn cons_tuples() {
+    // iproduct! yields flat tuples, matching the nested product
+    let flat: Vec<(u8, char, bool, i32)> = iproduct!(0..2, "ab".chars(), vec![true, false], 0..3)
+        .collect();
+    let nested = (0..2).cartesian_product("ab".chars())
+                       .cartesian_product(vec![true, false])
+                       .cartesian_product(0..3)
+                       .map(|(((a, b), c), d)| (a, b, c, d))
+                       .collect_vec();
+    assert_eq!(flat.len(), 24);
+    assert_eq!(flat, nested);
+
+    let zipped = (0..3).zip(3..6).zip(6..9).cons_tuples();
+    assert_eq!(zipped.len(), 3);
+    assert_iters_equal(zipped.rev(), vec![(2, 5, 8), (1, 4, 7), (0, 3, 6)].into_iter());
+
+    let deep = (0..2).zip(0..2).zip(0..2).cons_tuples().zip(0..2).cons_tuples();
+    assert_eq!(deep.collect_vec(), vec![(0, 0, 0, 0), (1, 1, 1, 1)]);
+}
+
 #[test]
 fn izip_macro() {
     let mut zip = izip!(0..3, 0..2, 0..2i8);
@@ -65,6 +88,56 @@ fn izip_macro() {
     let xs: [isize; 0] = [];
     let mut zip = izip!(0..3, 0..2, 0..2i8, &xs);
     assert!(zip.next().is_none());
+
+    let v = izip!(0..3, &["a", "b", "c"], 10..13).collect::<Vec<_>>();
+    assert_eq!(v, vec![(0, &"a", 10), (1, &"b", 11), (2, &"c", 12)]);
+
+    let mut zip = izip!(0..1, 1..2, 2..3, 3..4, 4..5, 5..6,
+                        6..7, 7..8, 8..9, 9..10, 10..11, 11..12);
+    assert_eq!(zip.size_hint(), (1, Some(1)));
+    assert_eq!(zip.next(), Some((0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11)));
+    assert_eq!(zip.next(), None);
+}
+
+#[test]
+fn izip_arities() {
+    // one argument is just its iterator, with or without a trailing comma
+    assert_iters_equal(izip!(0..3), 0..3);
+    assert_iters_equal(izip!(&[1, 2],), [1, 2].iter());
+
+    // non-Copy elements are moved through the flattening closure
+    let names = vec!["a".to_string(), "b".to_string()];
+    let v = izip!(names, vec![vec![1], vec![]], 0..).collect_vec();
+    assert_eq!(v, vec![("a".to_string(), vec![1], 0), ("b".to_string(), vec![], 1)]);
+
+    let mut zip = izip!(0..2, "ab".chars(), 2..4, 3..5, 4..6, 5..7, 6..8, &[7u8, 8],);
+    let first: (i32, char, i32, i32, i32, i32, i32, &u8) = zip.next().unwrap();
+    assert_eq!(first, (0, 'a', 2, 3, 4, 5, 6, &7));
+    assert_eq!(zip.size_hint(), (1, Some(1)));
+
+    // beyond the arity of the Zip tuple impls
+    let mut zip = izip!(0..1, 1..3, 2..4, 3..5, 4..6, 5..7, 6..8,
+                        7..9, 8..10, 9..11, 10..12, 11..13, 12..14);
+    let first: (i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32, i32) =
+        zip.next().unwrap();
+    // 13-tuples don't implement PartialEq, so compare the fields
+    let (a, b, c, d, e, f, g, h, i, j, k, l, m) = first;
+    assert_eq!([a, b, c, d, e, f, g, h, i, j, k, l, m], [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12]);
+    assert!(zip.next().is_none());
+}
+
+#[test]
+fn chain_macro() {
+    assert_eq!(chain!(0..2, vec![2, 3], Some(4)).collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+
+    let mut empty = chain!();
+    assert_eq!(empty.size_hint(), (0, Some(0)));
+    let elt: Option<i32> = empty.next();
+    assert_eq!(elt, None);
+
+    assert_iters_equal(chain!(0..3), 0..3);
+    assert_iters_equal(chain!(&[1, 2], &[], &[3],), [1, 2, 3].iter());
+    assert_eq!(chain!(0..2, 2..5, 5..7).size_hint(), (7, Some(7)));
 }
 
 #[test]
@@ -110,6 +183,34 @@ fn interleave() {
     assert_iters_equal(it, rs.iter());
 }
 
+#[test]
+fn interleave_all() {
+    // sources of very different lengths, and one that is empty from the start
+    let sources = vec![vec![1, 2, 3, 4, 5], vec![], vec![10], vec![20, 21]];
+    let mut it = it::interleave_all(sources);
+    assert_eq!(it.size_hint(), (8, Some(8)));
+    assert_eq!(it.by_ref().take(3).collect_vec(), vec![1, 10, 20]);
+    assert_eq!(it.size_hint(), (5, Some(5)));
+    assert_iters_equal(it, vec![2, 21, 3, 4, 5].into_iter());
+
+    // sources drop out of the rotation as they run dry
+    let it = it::interleave_all(vec![0..3, 10..12, 20..21]);
+    assert_eq!(it.size_hint(), (6, Some(6)));
+    assert_iters_equal(it, vec![0, 10, 20, 1, 11, 2].into_iter());
+
+    // size hints of inexact sources are summed bound by bound
+    let odd = |x: &i32| x % 2 == 1;
+    let it = it::interleave_all(vec![(0..4).filter(odd), (0..2).filter(odd)]);
+    assert_eq!(it.size_hint(), (0, Some(6)));
+
+    // a single source is the identity
+    assert_iters_equal(vec![0..5].into_iter().interleave_all(), 0..5);
+
+    let mut none = it::interleave_all(Vec::<Vec<u8>>::new());
+    assert_eq!(none.size_hint(), (0, Some(0)));
+    assert_eq!(none.next(), None);
+}
+
 #[test]
 fn times() {
     assert!(it::times(0).count() == 0);
@@ -144,6 +245,18 @@ fn dropping() {
     assert!(it.next().is_none());
     let mut it = xs.iter().dropping(5);
     assert!(it.next().is_none());
+    assert_eq!((0..10).dropping(3).next(), Some(3));
+}
+
+#[test]
+fn dropping_back() {
+    let xs = [1, 2, 3];
+    let mut it = xs.iter().cloned().dropping_back(2);
+    assert_eq!(it.next(), Some(1));
+    assert!(it.next().is_none());
+    let mut it = xs.iter().dropping_back(5);
+    assert!(it.next().is_none());
+    assert_eq!((0..10).dropping(3).dropping_back(3).collect_vec(), vec![3, 4, 5, 6]);
 }
 
 #[test]
@@ -158,6 +271,45 @@ fn intersperse() {
     assert!(it.next() == None);
 }
 
+#[test]
+fn intersperse_rev() {
+    assert_iters_equal([1, 2, 3].iter().cloned().intersperse(0).rev(),
+                       [3, 0, 2, 0, 1].iter().cloned());
+    assert_iters_equal([1, 2, 3, 4].iter().cloned().intersperse(0).rev(),
+                       [4, 0, 3, 0, 2, 0, 1].iter().cloned());
+    assert_iters_equal(Some(1).into_iter().intersperse(0).rev(), Some(1).into_iter());
+    assert_eq!((0..0).intersperse(9).next_back(), None);
+
+    // meet in the middle, from both ends
+    for len in 0..6 {
+        let expected = (1..len + 1).intersperse(0).collect_vec();
+        for front in 0..expected.len() + 1 {
+            let mut it = (1..len + 1).intersperse(0);
+            let mut v = it.by_ref().take(front).collect_vec();
+            let mut back = Vec::new();
+            loop {
+                assert_eq!(it.size_hint().0, expected.len() - v.len() - back.len());
+                match it.next_back() {
+                    Some(x) => back.push(x),
+                    None => break,
+                }
+            }
+            assert_eq!(it.next(), None);
+            back.reverse();
+            v.extend(back);
+            assert_eq!(v, expected);
+        }
+    }
+
+    let mut it = [1, 2].iter().cloned().intersperse(0);
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next_back(), Some(2));
+    assert_eq!(it.size_hint(), (1, Some(1)));
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+}
+
 #[test]
 fn linspace() {
     let mut iter = it::linspace::<f32>(0., 2., 3);
@@ -191,6 +343,94 @@ fn dedup() {
     assert_iters_equal(ys.iter(), xs.iter().dedup());
 }
 
+#[test]
+fn dedup_rev() {
+    let xs = [1, 1, 2, 2, 3];
+    assert_iters_equal(xs.iter().dedup().rev(), [3, 2, 1].iter());
+
+    // both ends meet inside the middle run, which is yielded once
+    let mut it = xs.iter().dedup();
+    assert_eq!(it.next(), Some(&1));
+    assert_eq!(it.next_back(), Some(&3));
+    assert_eq!(it.next_back(), Some(&2));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    // every pattern of front and back steps, on short sequences of 0..3
+    for n in 0..7 {
+        for seq in (0..3).cartesian_power(n) {
+            let expected = seq.iter().dedup().collect_vec();
+            for pattern in 0..1u32 << (n + 1) {
+                let mut it = seq.iter().dedup();
+                let (mut front, mut back): (Vec<&i32>, Vec<&i32>) = (Vec::new(), Vec::new());
+                for step in 0..n + 1 {
+                    if pattern & (1 << step) == 0 {
+                        front.extend(it.next());
+                    } else {
+                        back.extend(it.next_back());
+                    }
+                }
+                assert_eq!(it.next(), None);
+                front.extend(back.into_iter().rev());
+                assert_eq!(front, expected, "{:?} with pattern {:b}", seq, pattern);
+            }
+        }
+    }
+
+    // fold picks up the elements pending at both ends
+    let mut it = [0, 0, 1, 1, 2, 2, 3, 3].iter().cloned().dedup();
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next_back(), Some(3));
+    assert_eq!(it.size_hint(), (1, Some(4)));
+    assert_eq!(it.fold(Vec::new(), |mut v, x| { v.push(x); v }), vec![1, 2]);
+}
+
+#[test]
+fn coalesce_fold() {
+    let fold_vec = |v: Vec<i32>, x| { let mut v = v; v.push(x); v };
+    let xs = [0, 1, 1, 1, 2, 1, 3, 3];
+    assert_eq!(xs.iter().cloned().dedup().fold(Vec::new(), &fold_vec), vec![0, 1, 2, 1, 3]);
+    assert_eq!(xs.iter().cloned().dedup_by_key(|&x| x / 2).fold(Vec::new(), &fold_vec),
+               vec![0, 2, 1, 3]);
+
+    // merge runs of ascending neighbours into their sum
+    let merge = |x: (i32, i32), y: (i32, i32)| {
+        if x.1 + 1 == y.1 { Ok((x.0 + y.0, y.1)) } else { Err((x, y)) }
+    };
+    let runs = [1, 2, 3, 7, 8, 5, 0, 1].iter().map(|&x| (x, x)).coalesce(merge);
+    let lazy = runs.clone().map(|x| x.0).collect_vec();
+    assert_eq!(lazy, vec![6, 15, 5, 1]);
+    let folded = runs.clone().fold(Vec::new(), |mut v, x| { v.push(x.0); v });
+    assert_eq!(folded, lazy);
+
+    // fold after partial iteration still yields the pending element
+    let mut runs = runs;
+    assert_eq!(runs.next(), Some((6, 3)));
+    let mut rest = Vec::new();
+    runs.for_each(|x| rest.push(x.0));
+    assert_eq!(rest, vec![15, 5, 1]);
+
+    assert_eq!((0..0).dedup().fold(0, |acc, x| acc + x), 0);
+}
+
+#[test]
+fn dedup_by_key() {
+    let xs = [(0, 'a'), (1, 'b'), (1, 'c'), (2, 'd'), (1, 'e'), (1, 'f')];
+    let ys = [(0, 'a'), (1, 'b'), (2, 'd'), (1, 'e')];
+    let mut calls = 0;
+    {
+        let it = xs.iter().dedup_by_key(|&&(k, _)| { calls += 1; k });
+        assert_iters_equal(ys.iter(), it);
+    }
+    // the key is computed exactly once per element
+    assert_eq!(calls, xs.len());
+
+    // an owned key derived from a borrowed element
+    let words = ["Hello", "HELLO", "hello", "world", "World", "hello"];
+    let it = words.iter().dedup_by_key(|w| w.to_lowercase());
+    assert_iters_equal(it, ["Hello", "world", "hello"].iter());
+}
+
 #[test]
 fn batching() {
     let xs = [0, 1, 2, 1, 3];
@@ -219,6 +459,49 @@ fn group_by() {
     assert_iters_equal(gb, ans.into_iter());
 }
 
+#[test]
+fn group_by_owned() {
+    // single element runs, and a longer run at the very end
+    let groups = {
+        let xs = vec![String::from("a"), String::from("bb"), String::from("c"),
+                      String::from("dd"), String::from("ee")];
+        xs.into_iter().group_by(|s| s.len()).collect_vec()
+    };
+    // the source and the adaptor are gone, the groups remain usable
+    let mut it = groups.into_iter();
+    assert_eq!(it.next(), Some((1, vec![String::from("a")])));
+    assert_eq!(it.next(), Some((2, vec![String::from("bb")])));
+    assert_eq!(it.next(), Some((1, vec![String::from("c")])));
+    assert_eq!(it.next(), Some((2, vec![String::from("dd"), String::from("ee")])));
+    assert_eq!(it.next(), None);
+
+    let empty: Vec<i32> = vec![];
+    assert_eq!(empty.into_iter().group_by(|&x| x).next(), None);
+}
+
+#[test]
+fn group_by_out_of_order() {
+    let groups = (0..10).group_by(|&x| x / 3).collect_vec();
+
+    // read the groups in reverse
+    let keys = groups.iter().rev().map(|g| g.0).collect_vec();
+    assert_eq!(keys, vec![3, 2, 1, 0]);
+    let mut rev = groups.clone().into_iter().rev().map(|g| g.1);
+    assert_eq!(rev.next(), Some(vec![9]));
+    assert_eq!(rev.last(), Some(vec![0, 1, 2]));
+
+    // interleave reads from two groups
+    let mut groups = groups.into_iter().map(|g| g.1.into_iter());
+    let mut a = groups.next().unwrap();
+    let mut b = groups.next().unwrap();
+    assert_eq!((a.next(), b.next(), a.next(), b.next()), (Some(0), Some(3), Some(1), Some(4)));
+
+    // dropping a group early does not affect the ones after it
+    drop(groups.next());
+    assert_eq!(groups.next().unwrap().collect_vec(), vec![9]);
+    assert_eq!(a.collect_vec(), vec![2]);
+}
+
 #[test]
 fn put_back() {
     let xs = [0, 1, 1, 1, 2, 1, 3, 3];
@@ -229,6 +512,167 @@ fn put_back() {
     assert_iters_equal(pb, xs.iter().cloned());
 }
 
+#[test]
+fn put_back_double_ended() {
+    // slot empty
+    let mut pb = it::PutBack::new(0..3);
+    assert_eq!(pb.len(), 3);
+    assert_eq!(pb.next_back(), Some(2));
+    assert_eq!(pb.next(), Some(0));
+    assert_eq!(pb.next_back(), Some(1));
+    assert_eq!(pb.next_back(), None);
+    assert_eq!(pb.len(), 0);
+
+    // slot occupied: the put back element is the last one from the back
+    let mut pb = it::PutBack::new(0..3);
+    pb.put_back(-1);
+    assert_eq!(pb.size_hint(), (4, Some(4)));
+    assert_iters_equal(pb.rev(), vec![2, 1, 0, -1].into_iter());
+
+    // mixed consumption
+    let mut pb = it::PutBack::new(0..3);
+    assert_eq!(pb.next(), Some(0));
+    pb.put_back(10);
+    assert_eq!(pb.next_back(), Some(2));
+    assert_eq!(pb.len(), 2);
+    assert_eq!(pb.next(), Some(10));
+    assert_eq!(pb.next_back(), Some(1));
+    assert_eq!(pb.next(), None);
+    assert_eq!(pb.next_back(), None);
+}
+
+#[test]
+fn put_back_len_overflow() {
+    // a full slot on top of usize::MAX elements does not fit in a usize
+    let mut pb = it::PutBack::new(0..usize::MAX);
+    assert_eq!(pb.len(), usize::MAX);
+    pb.put_back(0);
+    assert_eq!(pb.size_hint(), (usize::MAX, None));
+    let len = std::panic::catch_unwind(move || pb.len());
+    assert!(len.is_err());
+
+    let mut pb = it::put_back_n(0..usize::MAX);
+    pb.put_back(0);
+    assert_eq!(pb.size_hint(), (usize::MAX, None));
+}
+
+#[test]
+fn put_back_n_double_ended() {
+    // stack empty
+    let mut pb = it::put_back_n(0..3);
+    assert_eq!(pb.len(), 3);
+    assert_iters_equal(pb.by_ref().rev(), (0..3).rev());
+    assert_eq!(pb.len(), 0);
+
+    // stack occupied: put back elements come last from the back
+    let mut pb = it::put_back_n(0..2);
+    pb.put_back(-1);
+    pb.put_back(-2);
+    assert_eq!(pb.len(), 4);
+    assert_iters_equal(pb.rev(), vec![1, 0, -1, -2].into_iter());
+
+    // mixed consumption
+    let mut pb = it::put_back_n(0..3);
+    pb.put_back(-1);
+    pb.put_back(-2);
+    assert_eq!(pb.next(), Some(-2));
+    assert_eq!(pb.next_back(), Some(2));
+    assert_eq!(pb.next_back(), Some(1));
+    assert_eq!(pb.len(), 2);
+    assert_eq!(pb.next_back(), Some(0));
+    assert_eq!(pb.next_back(), Some(-1));
+    assert_eq!(pb.next(), None);
+}
+
+#[test]
+fn put_back_n() {
+    let mut pb = it::put_back_n(3..6);
+    assert_eq!(pb.next(), Some(3));
+    pb.put_back(2);
+    pb.put_back(1);
+    pb.put_back(0);
+    assert_eq!(pb.size_hint(), (5, Some(5)));
+    // the most recently put back element comes out first
+    assert_iters_equal(pb.by_ref().take(3), 0..3);
+    pb.put_back(10);
+    assert_iters_equal(pb, vec![10, 4, 5].into_iter());
+
+    let mut pb = it::put_back_n(0..10);
+    assert_eq!(pb.peeking_take_while(|&x| x < 3).count(), 3);
+    assert_eq!(pb.next(), Some(3));
+}
+
+#[test]
+fn windows() {
+    let xs = [1, 2, 3, 4];
+    // n = 1 is a clone of the source
+    assert_eq!(xs.iter().cloned().windows(1).collect_vec(),
+               vec![vec![1], vec![2], vec![3], vec![4]]);
+    let it = xs.iter().cloned().windows(2);
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_eq!(it.collect_vec(), vec![vec![1, 2], vec![2, 3], vec![3, 4]]);
+    // n equal to the length yields one window
+    assert_eq!(xs.iter().cloned().windows(4).collect_vec(), vec![vec![1, 2, 3, 4]]);
+    // n larger than the length yields nothing
+    let mut it = xs.iter().cloned().windows(100);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+
+    let mut it = (0..10).windows(3);
+    assert_eq!(it.len(), 8);
+    assert_eq!(it.next(), Some(vec![0, 1, 2]));
+    assert_eq!(it.len(), 7);
+    assert_eq!(it.nth(6), Some(vec![7, 8, 9]));
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn windows_zero() {
+    (0..3).windows(0);
+}
+
+#[test]
+fn split_when() {
+    let xs = [1, 2, 5, 3, 4, 0];
+    let runs = xs.iter().split_when(|a, b| b < a).collect_vec();
+    assert_eq!(runs, vec![vec![&1, &2, &5], vec![&3, &4], vec![&0]]);
+
+    // the predicate sees both neighbors
+    let mut pairs = Vec::new();
+    let segments = (0..4).split_when(|&a, &b| { pairs.push((a, b)); false }).collect_vec();
+    assert_eq!(segments, vec![vec![0, 1, 2, 3]]);
+    assert_eq!(pairs, vec![(0, 1), (1, 2), (2, 3)]);
+
+    assert_eq!((0..0).split_when(|_, _| true).next(), None);
+    assert_eq!((0..3).split_when(|_, _| true).collect_vec(), vec![vec![0], vec![1], vec![2]]);
+    assert_eq!((0..3).split_when(|_, _| true).size_hint(), (0, Some(3)));
+}
+
+#[test]
+fn split_on() {
+    let sep = |&x: &i32| x == 0;
+    assert_eq!(vec![1, 2, 0, 3].into_iter().split_on(sep).collect_vec(),
+               vec![vec![1, 2], vec![3]]);
+    // leading, repeated and trailing separators yield empty segments
+    assert_eq!(vec![0, 1, 0, 0, 2, 0].into_iter().split_on(sep).collect_vec(),
+               vec![vec![], vec![1], vec![], vec![2], vec![]]);
+    assert_eq!(vec![0].into_iter().split_on(sep).collect_vec(),
+               vec![vec![], vec![]]);
+    // empty input yields no segments at all
+    assert_eq!(Vec::<i32>::new().into_iter().split_on(sep).next(), None);
+
+    let mut it = vec![1, 0].into_iter().split_on(sep);
+    assert_eq!(it.size_hint(), (0, Some(3)));
+    assert_eq!(it.next(), Some(vec![1]));
+    assert_eq!(it.size_hint(), (1, Some(1)));
+    assert_eq!(it.next(), Some(vec![]));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
 #[test]
 fn tee() {
     let xs  = [0, 1, 2, 3];
@@ -255,6 +699,19 @@ fn tee() {
     assert_iters_equal(t1.zip(t2), xs.iter().cloned().zip(xs.iter().cloned()));
 }
 
+#[test]
+fn tee_run_ahead() {
+    // a source that can't be cloned, with cloneable elements
+    let mut n = 0;
+    let source = it::repeat_call(move || { n += 1; n }).take(100);
+    let (t1, t2) = source.tee();
+    let first = t1.collect_vec();
+    assert_eq!(first, (1..101).collect_vec());
+    // the other half still sees every element, from the buffer
+    assert_eq!(t2.size_hint(), (100, Some(100)));
+    assert_eq!(t2.collect_vec(), first);
+}
+
 
 #[test]
 fn rciter() {
@@ -276,6 +733,44 @@ fn rciter() {
     assert_eq!(z.next(), Some((0, 1)));
 }
 
+#[test]
+fn rciter_fn() {
+    let rit = it::rciter(0..6);
+    let mut z = rit.clone().zip(rit);
+    assert_eq!(z.next(), Some((0, 1)));
+    assert_eq!(z.next(), Some((2, 3)));
+    assert_eq!(z.next(), Some((4, 5)));
+    assert_eq!(z.next(), None);
+}
+
+#[test]
+fn sharediter() {
+    use std::thread;
+
+    fn assert_send_sync<T: Send + Sync>(_: &T) { }
+
+    let it = it::sharediter(0..1000);
+    assert_send_sync(&it);
+    assert_eq!(it.size_hint(), (0, Some(1000)));
+    let handles = (0..2).map(|_| {
+        let it = it.clone();
+        thread::spawn(move || it.collect_vec())
+    }).collect_vec();
+    let mut parts = handles.into_iter().map(|h| h.join().unwrap()).collect_vec();
+    // each thread sees an increasing subsequence, and together they see all
+    for part in &parts {
+        assert!(part.windows(2).all(|w| w[0] < w[1]));
+    }
+    let mut all = parts.pop().unwrap();
+    all.extend(parts.pop().unwrap());
+    all.sort();
+    assert_iters_equal(all.into_iter(), 0..1000);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+
+    let sit = it::sharediter(0..4);
+    assert_eq!(sit.clone().zip(&sit).collect_vec(), vec![(0, 1), (2, 3)]);
+}
+
 #[test]
 fn slice() {
     assert_iters_equal((0..10).slice(..3), 0..3);
@@ -284,11 +779,74 @@ fn slice() {
     assert_iters_equal((0..10).slice(44..), 0..0);
 }
 
+#[test]
+fn get() {
+    assert_iters_equal((0..10).get(2..5), 2..5);
+    assert_iters_equal((0..10).get(2..=4), 2..5);
+    assert_iters_equal((0..10).get(..3), 0..3);
+    assert_iters_equal((0..10).get(..=3), 0..4);
+    assert_iters_equal((0..10).get(7..), 7..10);
+    assert_iters_equal((0..10).get(..), 0..10);
+    assert_iters_equal((0..10).get(8..15), 8..10);
+    assert_iters_equal((0..10).get(8..=20), 8..10);
+    assert_iters_equal((0..10).get(12..), 0..0);
+    assert_iters_equal((0..10).get(5..2), 0..0);
+    assert_iters_equal((0..10).get(3..=1), 0..0);
+    assert_eq!((0..10).get(5..2).size_hint(), (0, Some(0)));
+    assert_eq!((0..10).get(2..=4).size_hint(), (3, Some(3)));
+}
+
 #[test]
 fn step() {
     assert_iters_equal((0..10).step(1), (0..10));
     assert_iters_equal((0..10).step(2), (0..10).filter(|x: &i32| *x % 2 == 0));
     assert_iters_equal((0..10).step(10), 0..1);
+
+    let mut it = (0..10).step(3);
+    assert_eq!(it.size_hint(), (4, Some(4)));
+    assert_iters_equal(it.clone(), vec![0, 3, 6, 9].into_iter());
+    it.next();
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_eq!((0..0).step(3).size_hint(), (0, Some(0)));
+    assert_eq!((0..10).filter(|_| true).step(3).size_hint(), (0, Some(4)));
+}
+
+#[test]
+#[should_panic]
+fn step_zero() {
+    (0..10).step(0);
+}
+
+#[test]
+fn step_double_ended() {
+    assert_iters_equal((0..10).step(1).rev(), (0..10).rev());
+    assert_iters_equal((0..10).step(3).rev(), vec![9, 6, 3, 0].into_iter());
+    assert_iters_equal((0..11).step(3).rev(), vec![9, 6, 3, 0].into_iter());
+    assert_iters_equal((0..12).step(5).rev(), vec![10, 5, 0].into_iter());
+
+    let mut it = (0..11).step(3);
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.next_back(), Some(9));
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next_back(), Some(6));
+    assert_eq!(it.next(), Some(3));
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next_back(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn step_nth() {
+    let mut it = (0..20).step(3);
+    assert_eq!(it.nth(2), Some(6));
+    assert_eq!(it.next(), Some(9));
+    assert_eq!(it.nth(1), Some(15));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.nth(1), None);
+    assert_eq!(it.next(), None);
+
+    assert_eq!((0..5).step(1).nth(4), Some(4));
 }
 
 #[test]
@@ -351,6 +909,36 @@ fn merge_by_btree() {
     assert_iters_equal(results, expected.into_iter());
 }
 
+#[test]
+fn merge_by_stable() {
+    let left = vec![(1, 'a'), (2, 'a'), (2, 'b'), (4, 'a')];
+    let right = vec![(2, 'x'), (3, 'x'), (4, 'x')];
+    let cmp = |a: &(i32, char), b: &(i32, char)| a.0.cmp(&b.0);
+    let merged = left.iter().cloned().merge_by(right.iter().cloned(), cmp);
+    let expected = vec![(1, 'a'), (2, 'a'), (2, 'b'), (2, 'x'), (3, 'x'), (4, 'a'), (4, 'x')];
+    assert_eq!(merged.clone().collect_vec(), expected);
+    // fold agrees with next
+    assert_eq!(merged.fold(Vec::new(), |mut v, x| { v.push(x); v }), expected);
+
+    // one side empty
+    let merged = left.iter().cloned().merge_by(None, cmp);
+    assert_eq!(merged.fold(0, |n, _| n + 1), 4);
+    assert_iters_equal(Vec::new().into_iter().merge_by(right.iter().cloned(), cmp),
+                       right.iter().cloned());
+
+    // fold after partial iteration
+    let mut merged = (0..10).step(3).merge((0..10).step(2));
+    assert_eq!(merged.next(), Some(0));
+    assert_eq!(merged.next(), Some(0));
+    assert_eq!(merged.fold(Vec::new(), |mut v, x| { v.push(x); v }),
+               vec![2, 3, 4, 6, 6, 8, 9]);
+    let mut merged = (0..3).merge(0..1);
+    assert_eq!(merged.next(), Some(0));
+    assert_eq!(merged.next(), Some(0));
+    assert_eq!(merged.next(), Some(1));
+    assert_eq!(merged.fold(0, |acc, x| acc + x), 2);
+}
+
 #[test]
 fn join() {
     let many = [1, 2, 3];
@@ -387,6 +975,24 @@ fn multipeek() {
 
 }
 
+#[test]
+fn multipeek_reset() {
+    let mut multipeek = (1..10).multipeek();
+    assert_eq!(multipeek.peek(), Some(&1));
+    assert_eq!(multipeek.peek(), Some(&2));
+    assert_eq!(multipeek.peek(), Some(&3));
+    multipeek.reset_peek();
+    assert_eq!(multipeek.peek(), Some(&1));
+    multipeek.reset_peek();
+    assert_eq!(multipeek.next(), Some(1));
+    assert_eq!(multipeek.peek(), Some(&2));
+    assert_eq!(multipeek.peek(), Some(&3));
+    assert_eq!(multipeek.peek(), Some(&4));
+    multipeek.reset_peek();
+    assert_eq!(multipeek.next(), Some(2));
+    assert_eq!(multipeek.size_hint(), (7, Some(7)));
+}
+
 #[test]
 fn repeatn() {
     let s = "α";
@@ -433,13 +1039,28 @@ fn count_clones() {
     }
 }
 
-#[cfg(feature = "unstable")]
 #[test]
-#[should_panic]
-/// NOTE: Will only panic/overflow in debug builds
-fn enumerate_from_overflow() {
-    for _ in (0..1000).enumerate_from(0i8) {
-    }
+fn enumerate_from() {
+    let v = ['a', 'b'].iter().enumerate_from(100).collect_vec();
+    assert_eq!(v, vec![(100, &'a'), (101, &'b')]);
+
+    let mut it = (0..5).enumerate_from(10);
+    assert_eq!(it.len(), 5);
+    assert_eq!(it.next_back(), Some((14, 4)));
+    assert_eq!(it.next(), Some((10, 0)));
+    assert_eq!(it.next_back(), Some((13, 3)));
+    assert_eq!(it.len(), 2);
+    assert_iters_equal(it.rev(), vec![(12, 2), (11, 1)].into_iter());
+
+    assert_iters_equal((0..3).enumerate_from(0), (0..3).enumerate());
+}
+
+#[test]
+fn enumerate_from_saturating() {
+    let v = (0..3).enumerate_from(usize::MAX - 1).collect_vec();
+    assert_eq!(v, vec![(usize::MAX - 1, 0), (usize::MAX, 1), (usize::MAX, 2)]);
+    let v = (0..3).enumerate_from(usize::MAX - 1).rev().collect_vec();
+    assert_eq!(v, vec![(usize::MAX, 2), (usize::MAX, 1), (usize::MAX - 1, 0)]);
 }
 
 /// Like CharIndices iterator, except it yields slices instead
@@ -539,3 +1160,1654 @@ fn part() {
     assert_eq!(i, 3);
     assert_eq!(data, [9, 6, 3, 4, 5, 2, 7, 8, 1]);
 }
+
+#[test]
+fn combinations_size_hint() {
+    // an empty source used to underflow
+    assert_eq!((0..0).combinations().size_hint(), (0, Some(0)));
+    assert_eq!((0..1).combinations().size_hint(), (0, Some(0)));
+
+    let mut it = (0..5).combinations();
+    let mut remaining = 10;
+    assert_eq!(it.size_hint(), (remaining, Some(remaining)));
+    while let Some(_) = it.next() {
+        remaining -= 1;
+        assert_eq!(it.size_hint(), (remaining, Some(remaining)));
+    }
+    assert_eq!(remaining, 0);
+
+    let it = (0..10).filter(|&x| x < 5).combinations();
+    assert_eq!(it.size_hint(), (0, Some(45)));
+}
+
+#[test]
+fn tuple_combinations() {
+    let v = (1..5).tuple_combinations::<(_,)>().collect_vec();
+    assert_eq!(v, vec![(1,), (2,), (3,), (4,)]);
+
+    let it = (1..5).tuple_combinations::<(_, _)>();
+    assert_iters_equal(it, vec![(1, 2), (1, 3), (1, 4), (2, 3), (2, 4), (3, 4)].into_iter());
+
+    let it = (1..5).tuple_combinations::<(_, _, _, _)>();
+    assert_iters_equal(it, vec![(1, 2, 3, 4)].into_iter());
+
+    let mut it = (1..3).tuple_combinations::<(_, _, _)>();
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+
+    let mut it = (0..6).tuple_combinations::<(_, _, _)>();
+    assert_eq!(it.size_hint(), (20, Some(20)));
+    for n in (0..20).rev() {
+        assert!(it.next().is_some());
+        assert_eq!(it.size_hint(), (n, Some(n)));
+    }
+    assert_eq!(it.next(), None);
+}
+
+/// All **k**-combinations of **pool** in lexicographic order, as vectors.
+fn combinations_ref(pool: &[i32], k: usize) -> Vec<Vec<i32>> {
+    if k == 0 {
+        return vec![vec![]];
+    }
+    let mut result = Vec::new();
+    for (i, &x) in pool.iter().enumerate() {
+        for mut rest in combinations_ref(&pool[i + 1..], k - 1) {
+            rest.insert(0, x);
+            result.push(rest);
+        }
+    }
+    result
+}
+
+#[test]
+fn tuple_combinations_large() {
+    let pool = (0..9).collect_vec();
+
+    let it = pool.iter().cloned().tuple_combinations::<(_, _, _, _, _, _)>();
+    assert_eq!(it.size_hint(), (84, Some(84)));
+    let expected = combinations_ref(&pool, 6).into_iter()
+                       .map(|v| (v[0], v[1], v[2], v[3], v[4], v[5]));
+    it::assert_equal(it, expected);
+
+    let pool = (0..14).collect_vec();
+    let it = pool.iter().cloned()
+                 .tuple_combinations::<(_, _, _, _, _, _, _, _, _, _, _, _)>();
+    assert_eq!(it.size_hint(), (91, Some(91)));
+    let expected = combinations_ref(&pool, 12).into_iter()
+                       .map(|v| (v[0], v[1], v[2], v[3], v[4], v[5],
+                                 v[6], v[7], v[8], v[9], v[10], v[11]));
+    it::assert_equal(it, expected);
+
+    let mut it = (0..11).tuple_combinations::<(_, _, _, _, _, _, _, _, _, _, _, _)>();
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn tuple_combinations_nth() {
+    fn check<T>(len: usize) where
+        T: it::misc::TupleCollect<Item=usize> + PartialEq + Debug,
+    {
+        let all = (0..len).tuple_combinations::<T>().collect_vec();
+        for start in 0..all.len() + 2 {
+            for n in 0..all.len() + 3 {
+                let mut it = (0..len).tuple_combinations::<T>();
+                let mut reference = (0..len).tuple_combinations::<T>();
+                for _ in 0..start {
+                    assert_eq!(it.next(), reference.next());
+                }
+                let mut expected = None;
+                for _ in 0..n + 1 {
+                    expected = reference.next();
+                }
+                assert_eq!(it.nth(n), expected);
+                assert_eq!(it.size_hint(), reference.size_hint());
+                assert_eq!(it.next(), reference.next());
+            }
+        }
+    }
+    for len in 0..8 {
+        check::<(_,)>(len);
+        check::<(_, _)>(len);
+        check::<(_, _, _)>(len);
+        check::<(_, _, _, _)>(len);
+    }
+
+    // the combination count overflows, so nth steps one at a time
+    let mut it = (0..100000).tuple_combinations::<(_, _, _, _, _, _)>();
+    assert_eq!(it.size_hint(), (usize::MAX, None));
+    assert_eq!(it.nth(3), Some((0, 1, 2, 3, 4, 8)));
+    assert_eq!(it.nth(0), Some((0, 1, 2, 3, 4, 9)));
+
+    assert_eq!((0..10).tuple_combinations::<(_, _, _)>().nth(119), Some((7, 8, 9)));
+    assert_eq!((0..10).tuple_combinations::<(_, _, _)>().nth(120), None);
+    let mut it = (0..10).tuple_combinations::<(_, _)>();
+    assert_eq!(it.nth(usize::MAX), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn take_while_inclusive() {
+    // predicate never fails
+    let it = (0..4).take_while_inclusive(|_| true);
+    assert_iters_equal(it, 0..4);
+
+    // predicate fails on the first element
+    let v = (0..4).take_while_inclusive(|_| false).collect_vec();
+    assert_eq!(v, vec![0]);
+
+    let mut it = vec![1, 2, 0, 3, 4].into_iter().take_while_inclusive(|&x| x != 0);
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next(), Some(2));
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next(), None);
+    // resumed iteration after exhaustion
+    assert_eq!(it.next(), None);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+
+    // nothing is pulled from the source after the failing element
+    let mut source = vec![1, 2, 3, 4, 5].into_iter();
+    let taken = source.by_ref().take_while_inclusive(|&x| x < 3).collect_vec();
+    assert_eq!(taken, vec![1, 2, 3]);
+    assert_eq!(source.next(), Some(4));
+
+    // fused: next() keeps returning None though the source has more elements
+    fn fused<I: std::iter::FusedIterator>(it: I) -> I { it }
+    let mut n = 0;
+    let resuming = it::repeat_call(|| { n += 1; n }).map(|n| if n % 3 == 0 { None } else { Some(n) })
+                                                  .take(9);
+    let mut it = fused(resuming.take_while_inclusive(|x| x.is_some()));
+    assert_eq!(it.next(), Some(Some(1)));
+    assert_eq!(it.next(), Some(Some(2)));
+    assert_eq!(it.next(), Some(None));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn map_into() {
+    let xs = [1u8, 2, 3];
+    let mut it = xs.iter().cloned().map_into::<u32>();
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.next_back(), Some(3u32));
+    let v: Vec<u32> = it.collect();
+    assert_eq!(v, vec![1u32, 2]);
+}
+
+#[test]
+fn peeking_take_while() {
+    let mut pb = it::PutBack::new(0..10);
+    assert_eq!(pb.peeking_take_while(|&x| x < 4).collect_vec(), vec![0, 1, 2, 3]);
+    assert_eq!(pb.next(), Some(4));
+
+    let xs = [1, 2, 3, 0, 4];
+    let mut it = xs.iter();
+    assert_eq!(it.peeking_take_while(|x| **x != 0).count(), 3);
+    assert_eq!(it.next(), Some(&0));
+
+    let mut chars = "abc123".chars();
+    let alphas = chars.peeking_take_while(|c| c.is_alphabetic()).collect::<String>();
+    assert_eq!(alphas, "abc");
+    assert_eq!(chars.next(), Some('1'));
+}
+
+#[test]
+fn peeking_take_while_multipeek() {
+    let mut mp = (0..10).multipeek();
+    assert_eq!(mp.peeking_take_while(|&x| x < 2).collect_vec(), vec![0, 1]);
+    assert_eq!(mp.next(), Some(2));
+
+    // a rejected element leaves an in-progress peek sequence alone
+    let mut mp = (0..10).multipeek();
+    assert_eq!(mp.peek(), Some(&0));
+    assert_eq!(mp.peek(), Some(&1));
+    assert_eq!(mp.peeking_take_while(|_| false).next(), None);
+    assert_eq!(mp.peek(), Some(&2));
+
+    // an accepted element is consumed and resets the peek cursor
+    let mut mp = (0..10).multipeek();
+    assert_eq!(mp.peek(), Some(&0));
+    assert_eq!(mp.peek(), Some(&1));
+    assert_eq!(mp.peeking_take_while(|&x| x == 0).collect_vec(), vec![0]);
+    assert_eq!(mp.peek(), Some(&1));
+    assert_eq!(mp.next(), Some(1));
+
+    // pulling a fresh element into the buffer keeps peeking consistent
+    let mut mp = (0..10).multipeek();
+    assert_eq!(mp.peeking_take_while(|_| false).next(), None);
+    assert_eq!(mp.peek(), Some(&0));
+    assert_eq!(mp.peek(), Some(&1));
+    assert_eq!(mp.next(), Some(0));
+}
+
+#[test]
+fn product_size_hint() {
+    let mut prod = (0..3).cartesian_product(0..4);
+    let mut remaining = 12;
+    assert_eq!(prod.size_hint(), (remaining, Some(remaining)));
+    while let Some(_) = prod.next() {
+        remaining -= 1;
+        assert_eq!(prod.size_hint(), (remaining, Some(remaining)));
+    }
+    assert_eq!(remaining, 0);
+
+    let mut prod = (0..3).cartesian_product((0..10).filter(|&x| x < 4));
+    prod.next();
+    assert_eq!(prod.size_hint(), (0, Some(29)));
+}
+
+#[test]
+fn product_fold() {
+    let expected = (0..3).cartesian_product(0..4).collect_vec();
+    for consumed in 0..13 {
+        let mut prod = (0..3).cartesian_product(0..4);
+        for _ in 0..consumed {
+            prod.next();
+        }
+        let folded = prod.fold(Vec::new(), |mut acc, elt| { acc.push(elt); acc });
+        assert_eq!(&folded[..], &expected[consumed..]);
+    }
+
+    // empty inner or outer iterator
+    assert_eq!((0..3).cartesian_product(0..0).fold(0, |acc, _| acc + 1), 0);
+    assert_eq!((0..0).cartesian_product(0..3).fold(0, |acc, _| acc + 1), 0);
+}
+
+#[test]
+fn product_count() {
+    for consumed in 0..13 {
+        let mut prod = (0..3).cartesian_product(0..4);
+        for _ in 0..consumed {
+            prod.next();
+        }
+        assert_eq!(prod.count(), 12 - consumed);
+    }
+    assert_eq!((0..3).cartesian_product(0..0).count(), 0);
+    assert_eq!((0..0).cartesian_product(0..3).count(), 0);
+}
+
+#[test]
+fn product_last() {
+    for consumed in 0..13 {
+        let mut prod = (0..3).cartesian_product(0..4);
+        for _ in 0..consumed {
+            prod.next();
+        }
+        let expected = if consumed < 12 { Some((2, 3)) } else { None };
+        assert_eq!(prod.last(), expected);
+    }
+
+    // last element of a partially consumed final row
+    let mut prod = (0..2).cartesian_product(0..3);
+    for _ in 0..4 {
+        prod.next();
+    }
+    assert_eq!(prod.last(), Some((1, 2)));
+
+    assert_eq!((0..3).cartesian_product(0..0).last(), None);
+    assert_eq!((0..0).cartesian_product(0..3).last(), None);
+}
+
+#[test]
+fn product_rev() {
+    assert_iters_equal((0..2).cartesian_product(2..4).rev(),
+                       vec![(1, 3), (1, 2), (0, 3), (0, 2)].into_iter());
+    // unbalanced dimensions
+    assert_iters_equal((0..1).cartesian_product(0..3).rev(),
+                       vec![(0, 2), (0, 1), (0, 0)].into_iter());
+    assert_iters_equal((0..3).cartesian_product(0..1).rev(),
+                       vec![(2, 0), (1, 0), (0, 0)].into_iter());
+    assert_eq!((0..3).cartesian_product(0..0).next_back(), None);
+    assert_eq!((0..0).cartesian_product(0..3).next_back(), None);
+
+    // every mix of front and back consumption agrees with a deque of the
+    // elements, and so do the other methods in between
+    for a in 0..4 {
+        for b in 0..4 {
+            let n = a * b;
+            for pattern in 0..(1u32 << (n + 1)) {
+                let mut prod = (0..a).cartesian_product(0..b);
+                let mut expected = (0..a).cartesian_product(0..b)
+                                         .collect::<std::collections::VecDeque<_>>();
+                for step in 0..n + 1 {
+                    assert_eq!(prod.size_hint(), (expected.len(), Some(expected.len())));
+                    assert_eq!(prod.clone().count(), expected.len());
+                    assert_eq!(prod.clone().last(), expected.back().cloned());
+                    assert_eq!(prod.clone().collect_vec(), expected.iter().cloned().collect_vec());
+                    assert_eq!(prod.clone().fold(Vec::new(), |mut v, x| { v.push(x); v }),
+                               expected.iter().cloned().collect_vec());
+                    if pattern & (1 << step) == 0 {
+                        assert_eq!(prod.next(), expected.pop_front());
+                    } else {
+                        assert_eq!(prod.next_back(), expected.pop_back());
+                    }
+                }
+                assert_eq!(prod.next(), None);
+                assert_eq!(prod.next_back(), None);
+            }
+        }
+    }
+}
+
+#[test]
+fn positions() {
+    let data = [1, 2, 3, 3, 4, 6, 7, 9, 10];
+    let pred = |v: &i32| v % 2 == 0;
+    let expected = data.iter().enumerate()
+                       .filter(|&(_, v)| pred(v))
+                       .map(|(i, _)| i)
+                       .collect_vec();
+    assert_eq!(data.iter().positions(pred).collect_vec(), expected);
+    assert_eq!(data.iter().positions(pred).rev().collect_vec(),
+               expected.iter().cloned().rev().collect_vec());
+
+    // interleave front and back consumption in every pattern
+    for pattern in 0..(1 << expected.len()) {
+        let mut it = data.iter().positions(pred);
+        let mut front = 0;
+        let mut back = expected.len();
+        for step in 0..expected.len() {
+            if pattern & (1 << step) == 0 {
+                assert_eq!(it.next(), Some(expected[front]));
+                front += 1;
+            } else {
+                back -= 1;
+                assert_eq!(it.next_back(), Some(expected[back]));
+            }
+        }
+        assert_eq!(it.next(), None);
+        assert_eq!(it.next_back(), None);
+    }
+}
+
+#[test]
+fn tee_n() {
+    let xs = [0, 1, 2, 3, 4];
+    let mut ts = xs.iter().cloned().tee_n(3);
+    assert_eq!(ts.len(), 3);
+    assert_eq!(ts[0].next(), Some(0));
+    assert_eq!(ts[0].next(), Some(1));
+    assert_eq!(ts[0].next(), Some(2));
+    assert_eq!(ts[1].next(), Some(0));
+    assert_eq!(ts[0].buffer_len(), 3);
+    assert_eq!(ts[2].size_hint(), (5, Some(5)));
+    assert_eq!(ts[2].next(), Some(0));
+    assert_eq!(ts[0].buffer_len(), 2);
+    assert_eq!(ts[1].next(), Some(1));
+    assert_eq!(ts[1].len(), 3);
+
+    // dropping the slowest handle releases what it was holding on to
+    let t2 = ts.pop().unwrap();
+    drop(t2);
+    assert_eq!(ts[0].buffer_len(), 1);
+    assert_iters_equal(ts.pop().unwrap(), 2..5);
+    // the remaining handle still needs the last two elements
+    assert_eq!(ts[0].buffer_len(), 2);
+    assert_iters_equal(ts.pop().unwrap(), 3..5);
+
+    // n = 1 is a passthrough
+    let mut ts = (0..4).tee_n(1);
+    let mut t = ts.pop().unwrap();
+    assert_eq!(t.next(), Some(0));
+    assert_eq!(t.buffer_len(), 0);
+    assert_iters_equal(t, 1..4);
+
+    assert!((0..4).tee_n(0).is_empty());
+}
+
+#[test]
+fn exactly_one() {
+    assert_eq!((0..10).filter(|&x| x == 2).exactly_one().unwrap(), 2);
+
+    let err = (0..10).filter(|&x| x > 1 && x < 4).exactly_one().unwrap_err();
+    assert_eq!(err.to_string(), "got at least 2 elements when exactly one was expected");
+    assert_iters_equal(err, 2..4);
+
+    let err = (0..10).filter(|&x| x > 1 && x < 5).exactly_one().unwrap_err();
+    assert_eq!(err.size_hint(), (2, Some(8)));
+    assert_iters_equal(err, 2..5);
+
+    let err = (0..10).filter(|&_| false).exactly_one().unwrap_err();
+    assert_eq!(err.to_string(), "got zero elements when exactly one was expected");
+    assert_iters_equal(err, 0..0);
+}
+
+#[test]
+fn at_most_one() {
+    assert_eq!((0..10).filter(|&x| x == 2).at_most_one().unwrap(), Some(2));
+    assert_eq!((0..10).filter(|&_| false).at_most_one().unwrap(), None);
+
+    let err = (0..10).filter(|&x| x > 1 && x < 4).at_most_one().unwrap_err();
+    assert_eq!(err.to_string(), "got at least 2 elements when at most one was expected");
+    assert_iters_equal(err, 2..4);
+
+    let mut it = 0..10;
+    let mut err = it.by_ref().at_most_one().unwrap_err();
+    assert_eq!(err.next(), Some(0));
+    assert_eq!(err.next(), Some(1));
+    drop(err);
+    // only the first two elements were pulled
+    assert_eq!(it.next(), Some(2));
+}
+
+#[test]
+fn minmax() {
+    use it::MinMaxResult;
+
+    let empty: [i32; 0] = [];
+    assert_eq!(empty.iter().cloned().minmax().into_option(), None);
+    assert_eq!(empty.iter().cloned().minmax().into_iter().count(), 0);
+
+    let one = [7];
+    assert_eq!(one.iter().cloned().minmax().into_option(), Some((7, 7)));
+    assert_eq!(one.iter().cloned().minmax().into_iter().collect_vec(), vec![7]);
+
+    let many = [3, 1, 4, 1, 5, 9, 2, 6];
+    assert_eq!(many.iter().cloned().minmax(), MinMaxResult::MinMax(1, 9));
+    assert_eq!(many.iter().cloned().minmax().into_option(), Some((1, 9)));
+    assert_eq!(many.iter().minmax().map(|&x| x * 2), MinMaxResult::MinMax(2, 18));
+    let mut v = vec![0];
+    v.extend(many.iter().cloned().minmax());
+    assert_eq!(v, vec![0, 1, 9]);
+
+    // first minimum and last maximum
+    let data = [(1, 'a'), (0, 'b'), (2, 'c'), (0, 'd'), (2, 'e')];
+    assert_eq!(data.iter().map(|&(k, _)| k).minmax(), MinMaxResult::MinMax(0, 2));
+    let by_first = data.iter().map(|&(k, c)| Key(k, c)).minmax();
+    assert_eq!(by_first.map(|Key(_, c)| c), MinMaxResult::MinMax('b', 'e'));
+
+    #[derive(Debug)]
+    struct Key(i32, char);
+    impl PartialEq for Key { fn eq(&self, other: &Key) -> bool { self.0 == other.0 } }
+    impl Eq for Key { }
+    impl PartialOrd for Key {
+        fn partial_cmp(&self, other: &Key) -> Option<::std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Key {
+        fn cmp(&self, other: &Key) -> ::std::cmp::Ordering { self.0.cmp(&other.0) }
+    }
+}
+
+#[test]
+fn all_equal() {
+    assert!([2, 2, 2].iter().all_equal());
+    assert!([0; 0].iter().all_equal());
+    assert!(![1, 2, 1].iter().all_equal());
+
+    let mut it = vec![1, 1, 2, 3].into_iter();
+    assert!(!it.all_equal());
+    // the first differing element was the last one consumed
+    assert_eq!(it.next(), Some(3));
+}
+
+#[test]
+fn all_equal_value() {
+    assert_eq!([2, 2, 2].iter().all_equal_value(), Ok(&2));
+    assert_eq!([0; 0].iter().all_equal_value(), Err(None));
+    assert_eq!(vec![4, 4, 5, 4].into_iter().all_equal_value(), Err(Some((4, 5))));
+}
+
+#[test]
+fn equal() {
+    assert!(it::equal(0..3, vec![0, 1, 2]));
+    assert!(it::equal(0..0, Vec::<i32>::new()));
+    assert!(!it::equal(0..3, vec![0, 5, 2]));
+    assert!(!it::equal(0..3, 0..4));
+    assert!(!it::equal(0..4, 0..3));
+
+    // stops at the first mismatch
+    let mut rest = 0..10;
+    assert!(!it::equal(vec![0, 1, 7], rest.by_ref()));
+    assert_eq!(rest.next(), Some(3));
+}
+
+#[test]
+fn equal_cross_type() {
+    let strings = vec![String::from("a"), String::from("b")];
+    assert!(it::equal(&strings, &["a", "b"]));
+    it::assert_equal(strings.iter().map(|s| &s[..]), vec!["a", "b"]);
+}
+
+#[test]
+#[should_panic(expected = "elements differ at index 2: left: `3`, right: `4`")]
+fn assert_equal_mismatch() {
+    it::assert_equal(vec![1, 2, 3, 5], vec![1, 2, 4, 5]);
+}
+
+#[test]
+#[should_panic(expected = "right is longer by 2 element(s), starting at index 3 with `4`")]
+fn assert_equal_length() {
+    it::assert_equal(1..4, 1..6);
+}
+
+#[test]
+fn all_unique() {
+    assert!([1, 2, 3].iter().all_unique());
+    assert!([0; 0].iter().all_unique());
+
+    let mut it = vec![1, 2, 2, 3, 4].into_iter();
+    assert!(!it.all_unique());
+    // stops at the first repeated element
+    assert_eq!(it.next(), Some(3));
+}
+
+#[test]
+fn repeat_n() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    #[derive(Debug)]
+    struct Counted {
+        clones: Rc<Cell<usize>>,
+        drops: Rc<Cell<usize>>,
+    }
+
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            self.clones.set(self.clones.get() + 1);
+            Counted { clones: self.clones.clone(), drops: self.drops.clone() }
+        }
+    }
+
+    impl Drop for Counted {
+        fn drop(&mut self) {
+            self.drops.set(self.drops.get() + 1);
+        }
+    }
+
+    let clones = Rc::new(Cell::new(0));
+    let drops = Rc::new(Cell::new(0));
+    let elt = Counted { clones: clones.clone(), drops: drops.clone() };
+    let mut it = it::repeat_n(elt, 4);
+    for n in (0..4).rev() {
+        assert!(it.next().is_some());
+        assert_eq!(it.len(), n);
+    }
+    assert!(it.next().is_none());
+    assert_eq!(clones.get(), 3);
+    assert_eq!(drops.get(), 4);
+
+    // with n = 0 the element is dropped right away
+    let elt = Counted { clones: clones.clone(), drops: drops.clone() };
+    let it = it::repeat_n(elt, 0);
+    assert_eq!(drops.get(), 5);
+    assert_eq!(it.size_hint(), (0, Some(0)));
+
+    let mut it = it::repeat_n('a', 3);
+    assert_eq!(it.next_back(), Some('a'));
+    assert_eq!(it.len(), 2);
+
+    assert_eq!(it::repeat_n(7, 3).collect::<Vec<_>>(), vec![7, 7, 7]);
+    assert_eq!(it::repeat_n(0, 0).next(), None);
+}
+
+#[test]
+fn iterate() {
+    let mut calls = 0;
+    {
+        let it = it::iterate(1, |&x| { calls += 1; x * 3 });
+        assert_iters_equal(it.take(4), vec![1, 3, 9, 27].into_iter());
+    }
+    // taking k + 1 elements applies the function k times
+    assert_eq!(calls, 3);
+
+    assert_eq!(it::iterate(1, |&x| x * 2).take(5).collect::<Vec<_>>(),
+               vec![1, 2, 4, 8, 16]);
+
+    let it = it::iterate(0.5f64, |&x| x * x);
+    assert_eq!(it.size_hint(), (::std::usize::MAX, None));
+    let mut jt = it.clone();
+    assert_eq!(jt.next(), Some(0.5));
+    assert_eq!(format!("{:?}", it), "Iterate { state: 0.5, started: false }");
+}
+
+#[test]
+fn repeat_call() {
+    let mut counter = 0;
+    {
+        let it = it::repeat_call(|| { counter += 1; counter });
+        assert_eq!(it.size_hint(), (::std::usize::MAX, None));
+        assert_iters_equal(it.take(5), 1..6);
+    }
+    // the function is only called for the elements that were taken
+    assert_eq!(counter, 5);
+}
+
+#[test]
+fn tabulate() {
+    let it = it::tabulate(|i| i * i);
+    assert_eq!(it.size_hint(), (::std::usize::MAX, None));
+    assert_eq!(it.clone().take(4).collect::<Vec<_>>(), vec![0, 1, 4, 9]);
+
+    let mut it = it::tabulate(|i| format!("#{}", i)).skip(2);
+    assert_eq!(it.next(), Some("#2".to_string()));
+    let mut it2 = it.clone();
+    assert_eq!(it.next(), Some("#3".to_string()));
+    assert_eq!(it2.next(), Some("#3".to_string()));
+}
+
+#[test]
+fn unfold() {
+    // Fibonacci numbers up to a bound
+    let fibonacci = it::unfold((1u64, 1u64), |state| {
+        let (x, y) = *state;
+        if x > 100 {
+            return None;
+        }
+        *state = (y, x + y);
+        Some(x)
+    });
+    assert_eq!(fibonacci.size_hint(), (0, None));
+    assert_eq!(fibonacci.collect_vec(),
+               vec![1, 1, 2, 3, 5, 8, 13, 21, 34, 55, 89]);
+
+    let mut it = it::unfold(0, |n| { *n += 1; Some(*n) });
+    it.next();
+    assert_eq!(it.state, 1);
+}
+
+#[test]
+fn try_collect() {
+    let ok: Result<Vec<i32>, &str> = vec![Ok(1), Ok(2)].into_iter().try_collect();
+    assert_eq!(ok, Ok(vec![1, 2]));
+
+    let mut it = vec![Ok(1), Err("bad"), Ok(3), Err("worse")].into_iter();
+    let err: Result<Vec<i32>, &str> = it.by_ref().try_collect();
+    assert_eq!(err, Err("bad"));
+    // short-circuits on the first error
+    assert_eq!(it.next(), Some(Ok(3)));
+
+    // only the collection needs to be named
+    let parsed = "1 2 x".split(' ').map(|s| s.parse::<u8>()).try_collect::<Vec<_>>();
+    assert!(parsed.is_err());
+    let set = vec![Ok::<_, ()>(2), Ok(1), Ok(2)].into_iter().try_collect::<std::collections::HashSet<_>>();
+    assert_eq!(set.map(|s| s.len()), Ok(2));
+}
+
+#[test]
+fn partition_result() {
+    let results: Vec<Result<i32, &str>> = vec![Ok(1), Err("a"), Ok(2), Err("b")];
+    let (oks, errs): (Vec<_>, Vec<_>) = results.iter().cloned().partition_result();
+    assert_eq!(oks, vec![1, 2]);
+    assert_eq!(errs, vec!["a", "b"]);
+
+    // the two halves can be different collections
+    let (sum, errs): (Vec<i32>, String) = results.into_iter().partition_result();
+    assert_eq!(sum, vec![1, 2]);
+    assert_eq!(errs, "ab");
+
+    let (oks, errs): (Vec<i32>, Vec<String>) = Vec::<Result<i32, String>>::new().into_iter()
+                                                   .partition_result();
+    assert!(oks.is_empty() && errs.is_empty());
+}
+
+#[test]
+fn into_grouping_map() {
+    let sums = vec![(1, 2), (1, 3), (2, 10)].into_iter().into_grouping_map().sum();
+    assert_eq!(sums.len(), 2);
+    assert_eq!(sums[&1], 5);
+    assert_eq!(sums[&2], 10);
+
+    let words = vec![("a", "x"), ("b", "y"), ("a", "z")];
+    let joined = words.into_iter()
+                      .into_grouping_map()
+                      .fold(String::new(), |mut acc, key, val| {
+                          if acc.is_empty() {
+                              acc.push_str(key);
+                              acc.push(':');
+                          }
+                          acc.push_str(val);
+                          acc
+                      });
+    assert_eq!(joined["a"], "a:xz");
+    assert_eq!(joined["b"], "b:y");
+
+    // init is cloned once per key, not once per value
+    use std::cell::Cell;
+    struct CountClones<'a>(&'a Cell<usize>, usize);
+    impl<'a> Clone for CountClones<'a> {
+        fn clone(&self) -> Self {
+            self.0.set(self.0.get() + 1);
+            CountClones(self.0, self.1)
+        }
+    }
+    let clones = Cell::new(0);
+    let counts = vec![(1, ()), (2, ()), (1, ()), (1, ())].into_iter()
+        .into_grouping_map()
+        .fold(CountClones(&clones, 0), |acc, _, _| CountClones(acc.0, acc.1 + 1));
+    assert_eq!(counts[&1].1, 3);
+    assert_eq!(counts[&2].1, 1);
+    assert_eq!(clones.get(), 2);
+}
+
+#[test]
+fn kmerge() {
+    let its = (0..4).map(|s| (s..10).step(4));
+    assert_iters_equal(its.kmerge(), 0..10);
+
+    let empty: Vec<Vec<i32>> = vec![vec![], vec![]];
+    assert_eq!(empty.into_iter().kmerge().next(), None);
+
+    let its = vec![vec![5, 3, 1], vec![], vec![6, 4], vec![2]];
+    let it = its.into_iter().kmerge_by(|a, b| a > b);
+    assert_eq!(it.size_hint(), (6, Some(6)));
+    assert_iters_equal(it, (1..7).rev());
+}
+
+#[test]
+fn sorted_unstable() {
+    let xs = [5, 3, 9, 1, 3, 0, 7, 5, 5, 2];
+    let mut expected = xs.to_vec();
+    expected.sort();
+    it::assert_equal(xs.iter().cloned().sorted_unstable(), expected.iter().cloned());
+    it::assert_equal(xs.iter().cloned().sorted_unstable_by(|a, b| b.cmp(a)),
+                     expected.iter().cloned().rev());
+    assert_eq!(xs.iter().sorted_unstable().len(), xs.len());
+
+    // equal keys may be reordered, but the set of elements is the same
+    let pairs = (0..100).map(|i| (i % 7, i)).collect_vec();
+    let sorted = pairs.iter().cloned().sorted_unstable_by_key(|&(k, _)| k);
+    assert_eq!(sorted.size_hint(), (100, Some(100)));
+    let mut by_key = sorted.collect_vec();
+    assert!(by_key.windows(2).all(|w| w[0].0 <= w[1].0));
+    by_key.sort();
+    let mut all = pairs.clone();
+    all.sort();
+    assert_eq!(by_key, all);
+
+    assert_eq!(Vec::<u8>::new().into_iter().sorted_unstable().next(), None);
+}
+
+#[test]
+fn sum1_product1() {
+    assert_eq!(Vec::<i32>::new().into_iter().sum1::<i32>(), None);
+    assert_eq!(vec![1, 2, 3].into_iter().sum1(), Some(6));
+    assert_eq!(Vec::<f64>::new().into_iter().product1::<f64>(), None);
+    assert_eq!(vec![2.0, 0.5, 3.0].into_iter().product1(), Some(3.0));
+    assert_eq!(vec![7].into_iter().product1(), Some(7));
+}
+
+#[test]
+fn all_sorted() {
+    assert!((0..0).all_sorted());
+    assert!(Some(1).into_iter().all_sorted());
+    assert!([1, 1, 2, 3].iter().all_sorted());
+    assert!([3, 2, 2, 0].iter().all_sorted_by(|a, b| Some(b.cmp(a))));
+    assert!(["c", "bb", "aaa"].iter().all_sorted_by_key(|s| s.len()));
+    assert!(!["c", "bb", "aaa"].iter().all_sorted_by_key(|s| *s));
+
+    // stops right after the first out of order pair
+    let mut it = [1, 2, 5, 3, 4, 0].iter();
+    assert!(!it.all_sorted());
+    it::assert_equal(it, &[4, 0]);
+
+    let mut it = [1, 2, 3].iter();
+    assert!(!it.all_sorted_by(|_, _| None));
+    assert_eq!(it.next(), Some(&3));
+
+    // NaN is incomparable with everything
+    let mut it = [0.5, 1.0, std::f64::NAN, 2.0].iter();
+    assert!(!it.all_sorted());
+    assert_eq!(it.next(), Some(&2.0));
+    assert!(Some(std::f64::NAN).into_iter().all_sorted());
+}
+
+#[test]
+fn cartesian_power() {
+    let it = (0..2).cartesian_power(3);
+    assert_eq!(it.size_hint(), (8, Some(8)));
+    it::assert_equal(it, vec![vec![0, 0, 0], vec![0, 0, 1], vec![0, 1, 0], vec![0, 1, 1],
+                              vec![1, 0, 0], vec![1, 0, 1], vec![1, 1, 0], vec![1, 1, 1]]);
+
+    let mut it = (0..3).cartesian_power(2);
+    for i in (0..9).rev() {
+        assert!(it.next().is_some());
+        assert_eq!(it.size_hint(), (i, Some(i)));
+    }
+    assert_eq!(it.next(), None);
+
+    // n == 0 yields one empty vector regardless of the source
+    it::assert_equal((0..5).cartesian_power(0), vec![vec![]]);
+    it::assert_equal((0..0).cartesian_power(0), vec![vec![]]);
+    assert_eq!((0..0).cartesian_power(2).next(), None);
+    assert_eq!((0..1).cartesian_power(4).collect_vec(), vec![vec![0; 4]]);
+
+    // unknown upper bound and overflow
+    assert_eq!((0..10).filter(|_| true).cartesian_power(2).size_hint(), (0, Some(100)));
+    assert_eq!((0..1000).cartesian_power(10).size_hint(), (usize::MAX, None));
+
+    // the source is only pulled as needed
+    let mut it = (0..).cartesian_power(2);
+    assert_eq!(it.next(), Some(vec![0, 0]));
+    assert_eq!(it.nth(2), Some(vec![0, 3]));
+}
+
+#[test]
+fn flat_map_ok() {
+    let input: Vec<Result<i32, &str>> = vec![Ok(2), Err("x"), Ok(3)];
+    let mut it = input.into_iter().flat_map_ok(|n| 0..n);
+    assert_eq!(it.next(), Some(Ok(0)));
+    assert_eq!(it.size_hint(), (1, None));
+    assert_eq!(it.next(), Some(Ok(1)));
+    assert_eq!(it.next(), Some(Err("x")));
+    assert_eq!(it.next(), Some(Ok(0)));
+    assert_eq!(it.size_hint(), (2, Some(2)));
+    assert_eq!(it.collect_vec(), vec![Ok(1), Ok(2)]);
+
+    // errors before, between and after empty expansions
+    let input: Vec<Result<Vec<char>, i32>> =
+        vec![Err(1), Ok(vec![]), Ok(vec!['a']), Err(2), Ok(vec![]), Err(3)];
+    let v = input.into_iter().flat_map_ok(|v| v).collect_vec();
+    assert_eq!(v, vec![Err(1), Ok('a'), Err(2), Err(3)]);
+}
+
+#[test]
+fn sorted() {
+    fn double_ended_exact<I>(it: I) -> I
+        where I: DoubleEndedIterator + ExactSizeIterator + Clone + Debug
+    {
+        it
+    }
+
+    let xs = vec![5, 3, 9, 1, 3, 0, 7];
+    let mut manual = xs.clone();
+    manual.sort();
+
+    let it = double_ended_exact(xs.iter().cloned().sorted());
+    assert_eq!(it.len(), xs.len());
+    assert!(format!("{:?}", it).starts_with("SortedIter"));
+    assert_iters_equal(it.clone(), manual.iter().cloned());
+    assert_iters_equal(it.clone().rev().take(3), manual.iter().cloned().rev().take(3));
+    assert_eq!(it.clone().fold(0, |acc, x| acc * 10 + x), 133579);
+    assert_eq!(it.clone().count(), 7);
+    assert_eq!(it.clone().last(), Some(9));
+    let mut it = it;
+    assert_eq!(it.nth(2), Some(3));
+    assert_eq!(it.next_back(), Some(9));
+    assert_eq!(it.len(), 3);
+
+    let it = double_ended_exact(xs.iter().cloned().sorted_unstable_by_key(|&x| x));
+    assert_iters_equal(it, manual.iter().cloned());
+
+    // the stable sorts keep equal keys in their original order
+    let pairs = vec![(1, 'a'), (0, 'b'), (1, 'c'), (0, 'd')];
+    assert_iters_equal(pairs.iter().sorted_by_key(|p| p.0).map(|p| p.1), "bdac".chars());
+    assert_iters_equal(pairs.iter().sorted_by(|a, b| b.0.cmp(&a.0)).map(|p| p.1),
+                       "acbd".chars());
+}
+
+#[test]
+fn rev_sorted() {
+    let it = vec![3, 1, 2].into_iter().rev_sorted();
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_iters_equal(it, vec![3, 2, 1].into_iter());
+
+    let xs = vec![5, 3, 9, 1, 3, 0, 7];
+    assert_iters_equal(xs.iter().rev_sorted(), xs.iter().sorted().rev());
+
+    // equal elements keep their original order
+    #[derive(Debug)]
+    struct Tagged(u8, char);
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
+    }
+    impl Eq for Tagged { }
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> { Some(self.cmp(other)) }
+    }
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering { self.0.cmp(&other.0) }
+    }
+    let tagged = vec![Tagged(1, 'a'), Tagged(2, 'b'), Tagged(1, 'c'), Tagged(2, 'd')];
+    assert_iters_equal(tagged.into_iter().rev_sorted().map(|t| t.1), "bdac".chars());
+}
+
+#[test]
+fn filter_map_ok() {
+    let input: Vec<Result<i32, &str>> = vec![Ok(1), Ok(2), Err("e"), Ok(3)];
+    let odd_tens = |n: i32| if n % 2 == 1 { Some(n * 10) } else { None };
+    let it = input.iter().cloned().filter_map_ok(odd_tens);
+    assert_eq!(it.size_hint(), (0, Some(4)));
+    assert_eq!(it.collect_vec(), vec![Ok(10), Err("e"), Ok(30)]);
+
+    let v = input.iter().cloned().filter_map_ok(odd_tens).rev().collect_vec();
+    assert_eq!(v, vec![Ok(30), Err("e"), Ok(10)]);
+
+    // errors are kept even when every Ok value is dropped
+    let v = input.into_iter().filter_map_ok(|_| None::<()>).collect_vec();
+    assert_eq!(v, vec![Err("e")]);
+}
+
+#[test]
+fn cloned_free_fn() {
+    assert_eq!(it::cloned(&[1, 2, 3]).rev().collect::<Vec<_>>(), vec![3, 2, 1]);
+    let names = vec!["a".to_string(), "b".to_string()];
+    assert_eq!(it::cloned(&names).collect_vec(), names);
+    assert_eq!(it::cloned(&names).len(), 2);
+}
+
+#[test]
+fn write_join() {
+    use std::fmt;
+    use std::io;
+
+    let data = [1.5, 2.0, -3.25];
+    let mut s = String::new();
+    data.iter().write_join(&mut s, ", ").unwrap();
+    assert_eq!(s, data.iter().join(", "));
+
+    let mut s = String::new();
+    (0..0).write_join(&mut s, ", ").unwrap();
+    assert_eq!(s, "");
+    (0..1).write_join(&mut s, ", ").unwrap();
+    assert_eq!(s, "0");
+
+    /// Accepts **capacity** bytes, then fails.
+    struct Limited { capacity: usize, written: String }
+
+    impl fmt::Write for Limited {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            if s.len() > self.capacity {
+                return Err(fmt::Error);
+            }
+            self.capacity -= s.len();
+            self.written.push_str(s);
+            Ok(())
+        }
+    }
+
+    let mut w = Limited { capacity: 4, written: String::new() };
+    let mut it = 1..10;
+    assert!(it.write_join(&mut w, "-").is_err());
+    assert_eq!(w.written, "1-2-");
+    // the element whose output failed is consumed, but nothing after it
+    assert_eq!(it.next(), Some(4));
+
+    let mut out = Vec::new();
+    data.iter().write_join_io(&mut out, ", ").unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), data.iter().join(", "));
+
+    let mut buf = [0u8; 3];
+    let mut it = ["ab", "cd"].iter();
+    {
+        let mut sink = &mut buf[..];
+        let err = it.write_join_io(&mut sink, "+").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::WriteZero);
+    }
+    assert_eq!(&buf, b"ab+");
+    assert_eq!(it.next(), None);
+
+    let mut out = Vec::new();
+    (0..0).write_join_io(&mut out, ", ").unwrap();
+    assert!(out.is_empty());
+}
+
+#[test]
+fn fold_while() {
+    use it::FoldWhile::{Continue, Done};
+    use std::cell::Cell;
+
+    let pulled = Cell::new(0);
+    let mut source = (1..100).inspect(|_| pulled.set(pulled.get() + 1));
+    let r = source.fold_while(String::new(), |mut acc, x| {
+        acc.push_str(&x.to_string());
+        if acc.len() >= 5 { Done(acc) } else { Continue(acc) }
+    });
+    assert_eq!(r, Done("12345".to_string()));
+    // nothing is pulled after Done
+    assert_eq!(pulled.get(), 5);
+
+    // sum until the running total exceeds 20; the iterator stops there
+    let mut it = 1..10;
+    let r = it.fold_while(0, |acc, x| if acc + x > 20 { Done(acc) } else { Continue(acc + x) });
+    assert_eq!(r, Done(15));
+    assert_eq!(it.next(), Some(7));
+
+    let r = (0..0).fold_while(7, |acc, x| Done(acc + x));
+    assert_eq!(r, Continue(7));
+    assert!(!r.is_done());
+    assert_eq!(r.into_inner(), 7);
+
+    let r = [1, 2, 3].iter().fold_while(0, |acc, &x| Continue(acc + x));
+    assert_eq!(r, Continue(6));
+
+    // Done on the last element is still Done
+    let r = (0..3).fold_while(0, |acc, x| if x == 2 { Done(acc) } else { Continue(acc + x) });
+    assert_eq!(r, Done(1));
+}
+
+#[test]
+fn peekable_back() {
+    let mut it = it::peekable_back(0..5);
+    assert_eq!(it.peek_back(), Some(&4));
+    assert_eq!(it.peek_back(), Some(&4));
+    assert_eq!(it.len(), 5);
+    assert_eq!(it.next_back(), Some(4));
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.peek_back(), Some(&3));
+    assert_iters_equal(it, 1..4);
+
+    // the front reaches the peeked element
+    let mut it = (0..2).peekable_back();
+    assert_eq!(it.peek_back(), Some(&1));
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next(), None);
+    assert_eq!(it.peek_back(), None);
+}
+
+#[test]
+fn unique_by_owned() {
+    use std::cell::Cell;
+    use std::hash::{Hash, Hasher};
+
+    // counts how often the key is cloned into the set of seen keys
+    struct Key<'a>(u32, &'a Cell<usize>);
+
+    impl<'a> PartialEq for Key<'a> {
+        fn eq(&self, other: &Self) -> bool { self.0 == other.0 }
+    }
+
+    impl<'a> Eq for Key<'a> { }
+
+    impl<'a> Hash for Key<'a> {
+        fn hash<H: Hasher>(&self, h: &mut H) { self.0.hash(h) }
+    }
+
+    impl<'a> Clone for Key<'a> {
+        fn clone(&self) -> Self {
+            self.1.set(self.1.get() + 1);
+            Key(self.0, self.1)
+        }
+    }
+
+    let clones = Cell::new(0);
+    let data = (0..100).map(|i| (i, Key(i % 10, &clones))).collect_vec();
+    let unique = data.iter().unique_by_owned(|&&(_, ref key)| key).map(|x| x.0);
+    assert_iters_equal(unique, 0..10);
+    // only the first of each ten duplicates was cloned
+    assert_eq!(clones.get(), 10);
+
+    let words = "b a b c a".split(' ').map(String::from).collect_vec();
+    let unique = words.iter().unique_by_owned(|s| s.as_str()).cloned().collect_vec();
+    assert_eq!(unique, vec!["b", "a", "c"]);
+}
+
+#[test]
+fn group_by_size_hint() {
+    fn assert_fused<I: std::iter::FusedIterator>(_: &I) { }
+
+    let mut groups = vec![1, 1, 2, 3, 3, 3].into_iter().group_by(|&x| x);
+    assert_fused(&groups);
+    assert_eq!(groups.size_hint(), (1, Some(6)));
+    let (_, group) = groups.next().unwrap();
+    assert_eq!(group.len(), 2);
+    // one element of the next run is already buffered
+    assert_eq!(groups.size_hint(), (1, Some(4)));
+    let (_, group) = groups.next().unwrap();
+    assert_eq!(group.into_iter().rev().size_hint(), (1, Some(1)));
+    let (_, group) = groups.next().unwrap();
+    assert_eq!(group.into_iter().len(), 3);
+    assert_eq!(groups.size_hint(), (0, Some(0)));
+    assert_eq!(groups.next(), None);
+    assert_eq!(groups.next(), None);
+}
+
+/// Check that the size hint of `it` holds at each step of the iteration.
+fn check_size_hint<I: Iterator + Clone>(mut it: I) {
+    loop {
+        let (lo, hi) = it.size_hint();
+        let rest = it.clone().count();
+        assert!(lo <= rest, "lower bound {} but {} elements left", lo, rest);
+        if let Some(hi) = hi {
+            assert!(rest <= hi, "upper bound {} but {} elements left", hi, rest);
+        }
+        if it.next().is_none() {
+            break;
+        }
+    }
+}
+
+#[test]
+fn batching_with_hint() {
+    // pairing, dropping an unpaired last element
+    for n in 0..6 {
+        let pairs = (0..n).batching_with_hint(
+            |it| it.next().and_then(|x| it.next().map(|y| (x, y))),
+            |(lo, hi)| (lo / 2, hi.map(|hi| hi / 2)));
+        assert_eq!(pairs.size_hint(), (n / 2, Some(n / 2)));
+        check_size_hint(pairs);
+    }
+
+    // summing chunks of three, keeping a partial last chunk
+    for n in 0..8 {
+        let sums = (0..n).batching_with_hint(
+            |it| it.next().map(|x| x + it.take(2).sum::<i32>()),
+            |(lo, hi)| ((lo + 2) / 3, hi.map(|hi| (hi + 2) / 3)));
+        check_size_hint(sums.clone());
+        assert_eq!(sums.map(|_| 1).sum::<i32>(), (n + 2) / 3);
+    }
+
+    // parsing length-prefixed records; each has at least the length
+    let data = [2, 10, 20, 0, 1, 30, 3, 40, 50, 60];
+    let records = data.iter().batching_with_hint(
+        |it| it.next().map(|&len| it.take(len).cloned().collect_vec()),
+        |(_, hi)| (0, hi));
+    check_size_hint(records.clone());
+    assert_eq!(records.collect_vec(),
+               vec![vec![10, 20], vec![], vec![30], vec![40, 50, 60]]);
+}
+
+#[test]
+fn chunk_by_key() {
+    let mut chunks = vec![1, 1, 2, 2, 2, 3].into_iter().chunk_by_key(|&x| x);
+    assert_eq!(chunks.size_hint(), (1, Some(6)));
+    assert_eq!(chunks.next(), Some((1, vec![1, 1])));
+    assert_eq!(chunks.next(), Some((2, vec![2, 2, 2])));
+    assert_eq!(chunks.next(), Some((3, vec![3])));
+    assert_eq!(chunks.size_hint(), (0, Some(0)));
+    assert_eq!(chunks.next(), None);
+
+    let words = ["ant", "ape", "bee", "cat", "cow"];
+    let chunks = words.iter().chunk_by_key(|w| w.as_bytes()[0]).map(|(_, v)| v.len());
+    assert_iters_equal(chunks, vec![2, 1, 2].into_iter());
+}
+
+#[test]
+fn process_results() {
+    // the error stops the processing
+    let data = vec![Ok(1), Ok(2), Err("bad"), Ok(3)];
+    let mut seen = Vec::new();
+    let res = data.iter().cloned().process_results(|it| {
+        for x in it {
+            seen.push(x);
+        }
+    });
+    assert_eq!(res, Err("bad"));
+    assert_eq!(seen, vec![1, 2]);
+    assert_eq!(it::process_results(data.iter().cloned(), |it| it.count()), Err("bad"));
+
+    let data: Vec<Result<i32, ()>> = vec![Ok(1), Ok(2), Ok(3)];
+    assert_eq!(it::process_results(data.clone(), |it| it.sum::<i32>()), Ok(6));
+    assert_eq!(data.iter().cloned().process_results(|it| {
+        assert_eq!(it.size_hint(), (0, Some(3)));
+        it.collect_vec()
+    }), Ok(vec![1, 2, 3]));
+
+    let max = "4 8 2".split(' ').map(|s| s.parse::<i32>()).process_results(|it| it.max());
+    assert_eq!(max, Ok(Some(8)));
+    assert!("4 x 2".split(' ').map(|s| s.parse::<i32>()).process_results(|it| it.max()).is_err());
+}
+
+#[test]
+fn pad_using() {
+    let pad = |i| 10 * i;
+    let it = (0..3).pad_using(5, pad);
+    assert_eq!(it.len(), 5);
+    assert_iters_equal(it.clone(), vec![0, 1, 2, 30, 40].into_iter());
+    assert_iters_equal(it.rev(), vec![40, 30, 2, 1, 0].into_iter());
+
+    // no padding needed
+    let it = (0..4).pad_using(2, pad);
+    assert_eq!(it.len(), 4);
+    assert_iters_equal(it.clone(), 0..4);
+    assert_iters_equal(it.rev(), (0..4).rev());
+
+    // mixed front and back consumption
+    let mut it = (0..2).pad_using(6, pad);
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next_back(), Some(50));
+    assert_eq!(it.len(), 4);
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next_back(), Some(40));
+    assert_eq!(it.next(), Some(20));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.next_back(), Some(30));
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next(), None);
+    assert_eq!(it.next_back(), None);
+
+    let mut it = (0..4).pad_using(5, pad);
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next(), Some(1));
+    assert_eq!(it.next_back(), Some(40));
+    assert_eq!(it.next_back(), Some(3));
+    assert_iters_equal(it, vec![2].into_iter());
+
+    let mut it = (0..4).pad_using(2, pad);
+    assert_eq!(it.next(), Some(0));
+    assert_eq!(it.next_back(), Some(3));
+    assert_eq!(it.next_back(), Some(2));
+    assert_eq!(it.next_back(), Some(1));
+    assert_eq!(it.next(), None);
+
+    assert_eq!((0..0).pad_using(0, pad).next(), None);
+}
+
+#[test]
+fn take_last_n() {
+    assert_iters_equal((0..100).take_last_n(3), 97..100);
+    assert_iters_equal((0..2).take_last_n(3), 0..2);
+    assert_iters_equal((0..3).take_last_n(3), 0..3);
+    assert_eq!((0..10).take_last_n(0).len(), 0);
+    assert_eq!((0..0).take_last_n(2).len(), 0);
+
+    // the buffer wraps around many times
+    let last = (0..1_000_003).take_last_n(5);
+    assert_eq!(last.as_slice().len(), 5);
+    assert_iters_equal(last, 999_998..1_000_003);
+    assert_iters_equal((0..1_000_003).take_last_n(5).rev(), (999_998..1_000_003).rev());
+}
+
+#[test]
+fn find_or_last_first() {
+    let data = vec![1, 2, 3, 4];
+    let mut it = data.iter().cloned();
+    assert_eq!(it.by_ref().find_or_last(|&x| x == 2), Some(2));
+    // stops at the match
+    assert_eq!(it.next(), Some(3));
+    assert_eq!(data.iter().cloned().find_or_last(|&x| x > 10), Some(4));
+    assert_eq!((0..0).find_or_last(|_| true), None);
+
+    let mut it = data.iter().cloned();
+    assert_eq!(it.by_ref().find_or_first(|&x| x == 2), Some(2));
+    assert_eq!(it.next(), Some(3));
+    assert_eq!(data.iter().cloned().find_or_first(|&x| x == 1), Some(1));
+    assert_eq!(data.iter().cloned().find_or_first(|&x| x > 10), Some(1));
+    assert_eq!((0..0).find_or_first(|_| true), None);
+}
+
+#[test]
+fn dedup_by_with_count() {
+    let data = vec![1.1, 1.9, 2.2];
+    let runs = data.into_iter().dedup_by_with_count(|a: &f64, b: &f64| a.floor() == b.floor());
+    assert_eq!(runs.collect_vec(), vec![(2, 1.1), (1, 2.2)]);
+
+    // within an epsilon of the first element of the run
+    let data = vec![0.0, 0.4, 0.8, 1.0, 5.0, 5.1];
+    let mut runs = data.iter().dedup_by_with_count(|&&a, &&b| (b - a) < 0.5f64);
+    assert_eq!(runs.size_hint(), (1, Some(6)));
+    assert_eq!(runs.next(), Some((2, &0.0)));
+    assert_eq!(runs.next(), Some((2, &0.8)));
+    assert_eq!(runs.next(), Some((2, &5.0)));
+    assert_eq!(runs.next(), None);
+    assert_eq!(runs.next(), None);
+
+    let counts = "aAbBBa".chars().dedup_by_with_count(|a, b| a.eq_ignore_ascii_case(b));
+    assert_eq!(counts.collect_vec(), vec![(2, 'a'), (3, 'b'), (1, 'a')]);
+    assert_eq!((0..0).dedup_by_with_count(|a, b| a == b).next(), None);
+}
+
+#[test]
+fn position_runs() {
+    let runs = |data: &[i32]| data.iter().position_runs(|&x| x == 1).collect_vec();
+    assert_eq!(runs(&[0, 1, 1, 0, 1]), vec![1..3, 4..5]);
+    assert_eq!(runs(&[1, 1, 0, 0, 1, 0]), vec![0..2, 4..5]);
+    assert_eq!(runs(&[0, 0, 1, 1, 1]), vec![2..5]);
+    assert_eq!(runs(&[1, 1, 1]), vec![0..3]);
+    assert_eq!(runs(&[0, 0]), vec![]);
+    assert_eq!(runs(&[]), vec![]);
+
+    let mut it = [1, 0, 1, 0, 1].iter().position_runs(|&x| x == 1);
+    assert_eq!(it.size_hint(), (0, Some(3)));
+    assert_eq!(it.next(), Some(0..1));
+    assert_eq!(it.size_hint(), (0, Some(2)));
+    assert_eq!(it.by_ref().last(), Some(4..5));
+    assert_eq!(it.next(), None);
+}
+
+#[test]
+fn minmax_by() {
+    use it::MinMaxResult::{NoElements, OneElement, MinMax};
+
+    #[derive(Debug, PartialEq)]
+    struct Item { name: &'static str, weight: f64 }
+
+    let items = [Item { name: "a", weight: 2.0 }, Item { name: "b", weight: 1.0 },
+                 Item { name: "c", weight: 3.0 }, Item { name: "d", weight: 1.0 },
+                 Item { name: "e", weight: 3.0 }, Item { name: "f", weight: 2.5 }];
+    let by_weight = |a: &&Item, b: &&Item| a.weight.partial_cmp(&b.weight).unwrap();
+    match items.iter().minmax_by(by_weight) {
+        // the first minimum and the last maximum
+        MinMax(min, max) => assert_eq!((min.name, max.name), ("b", "e")),
+        res => panic!("unexpected {:?}", res),
+    }
+    assert_eq!(items[..1].iter().minmax_by(by_weight), OneElement(&items[0]));
+    assert_eq!(items[..0].iter().minmax_by(by_weight), NoElements);
+
+    for n in 1..items.len() {
+        let res = items[..n].iter().minmax_by(by_weight).into_option().unwrap();
+        let min = items[..n].iter().min_by(by_weight).unwrap();
+        let max = items[..n].iter().max_by(by_weight).unwrap();
+        assert_eq!((res.0.name, res.1.name), (min.name, max.name));
+    }
+}
+
+#[test]
+fn minmax_full() {
+    use it::MinMaxResult::{NoElements, OneElement, MinMax};
+
+    // duplicate extremes: the first minimum and the last maximum
+    let data = [4, 0, 9, 0, 5, 9, 3];
+    assert_eq!(data.iter().cloned().minmax_full(), MinMax((1, 0), (5, 9)));
+    assert_eq!(data[..6].iter().cloned().minmax_full(), MinMax((1, 0), (5, 9)));
+    assert_eq!([7, 7].iter().minmax_full(), MinMax((0, &7), (1, &7)));
+    assert_eq!([7].iter().minmax_full(), OneElement((0, &7)));
+    assert_eq!((0..0).minmax_full(), NoElements);
+
+    assert_eq!(data.iter().minmax_full_by(|a, b| b.cmp(a)), MinMax((2, &9), (3, &0)));
+
+    let mut calls = 0;
+    let res = data.iter().minmax_full_by_key(|&&x| { calls += 1; (x as i32 - 4).abs() });
+    assert_eq!(res, MinMax((0, &4), (5, &9)));
+    assert_eq!(calls, data.len());
+
+    for n in 0..data.len() {
+        let res = data[..n].iter().minmax_full();
+        let min = data[..n].iter().enumerate().min_by_key(|p| p.1);
+        let max = data[..n].iter().enumerate().max_by_key(|p| p.1);
+        assert_eq!(res.into_option(), min.and_then(|min| max.map(|max| (min, max))));
+    }
+}
+
+#[test]
+fn checked_sum_product() {
+    assert_eq!(vec![100u8, 100, 55].into_iter().checked_sum::<u8>(), Some(255));
+    assert_eq!(vec![100u8, 100, 56].into_iter().checked_sum::<u8>(), None);
+    assert_eq!([-128i8, 127].iter().checked_sum::<i8>(), Some(-1));
+    assert_eq!([-128i8, -1].iter().checked_sum::<i8>(), None);
+    assert_eq!(Vec::<u64>::new().into_iter().checked_sum::<u64>(), Some(0));
+    assert_eq!(Vec::<u64>::new().iter().checked_product::<u64>(), Some(1));
+    assert_eq!([usize::max_value(), 2].iter().checked_product::<usize>(), None);
+    assert_eq!([16u8, 15].iter().checked_product::<u8>(), Some(240));
+    assert_eq!([16u8, 16].iter().checked_product::<u8>(), None);
+    assert_eq!([1u128 << 64, 1 << 63].iter().checked_product::<u128>(), Some(1 << 127));
+
+    // the rest is not consumed after an overflow
+    let mut it = vec![250u8, 10, 1, 2].into_iter();
+    assert_eq!(it.by_ref().checked_sum::<u8>(), None);
+    assert_eq!(it.next(), Some(1));
+}
+
+#[test]
+fn cached() {
+    use std::cell::Cell;
+    use std::rc::Rc;
+
+    let pulled = Cell::new(0);
+    let source = (0..20).inspect(|_| pulled.set(pulled.get() + 1));
+    let mut first = source.cached();
+    let second = first.clone();
+    assert_eq!(first.size_hint(), (20, Some(20)));
+    assert_iters_equal(first.by_ref().take(10), 0..10);
+    assert_eq!(pulled.get(), 10);
+    assert_eq!(second.size_hint(), (20, Some(20)));
+    assert_eq!(first.size_hint(), (10, Some(10)));
+
+    // the second handle replays the cache, then extends it
+    let mut second = second;
+    assert_iters_equal(second.by_ref().take(15), 0..15);
+    assert_eq!(pulled.get(), 15);
+    assert_iters_equal(first, 10..20);
+    assert_eq!(pulled.get(), 20);
+    assert_iters_equal(second.replay(), 0..20);
+    assert_iters_equal(second, 15..20);
+    assert_eq!(pulled.get(), 20);
+
+    // dropping every handle drops the cache and the source
+    let token = Rc::new(());
+    let held = token.clone();
+    let handle = (0..3).map(move |x| { let _ = &held; x }).cached();
+    let other = handle.replay();
+    assert_eq!(Rc::strong_count(&token), 2);
+    drop(handle);
+    assert_eq!(Rc::strong_count(&token), 2);
+    drop(other);
+    assert_eq!(Rc::strong_count(&token), 1);
+}
+
+#[test]
+fn diff_with() {
+    use it::Diff;
+
+    assert!(it::diff_with(0..3, vec![0, 1, 2], |a, b| a == b).is_none());
+    assert!(it::diff_with(0..0, "".chars(), |_, _| false).is_none());
+
+    match it::diff_with(0..5, vec![0, 1, 7, 3], |a, b| a == b) {
+        Some(Diff::FirstMismatch(index, i, j)) => {
+            assert_eq!(index, 2);
+            assert_iters_equal(i, 2..5);
+            assert_iters_equal(j, vec![7, 3].into_iter());
+        }
+        _ => panic!("expected FirstMismatch"),
+    }
+
+    match it::diff_with(0..5, 0..3, |a, b| a == b) {
+        Some(Diff::Shorter(index, i)) => {
+            assert_eq!(index, 3);
+            assert_iters_equal(i, 3..5);
+        }
+        _ => panic!("expected Shorter"),
+    }
+
+    // the comparison may be between different types
+    match it::diff_with(&[1, 2], &["1", "2", "3", "4"], |a, b| a.to_string() == **b) {
+        Some(Diff::Longer(index, j)) => {
+            assert_eq!(index, 2);
+            assert_iters_equal(j, ["3", "4"].iter());
+        }
+        _ => panic!("expected Longer"),
+    }
+}
+
+#[test]
+fn pairwise() {
+    let deltas = vec![10, 13, 9].into_iter().pairwise().map(|(a, b)| b - a).collect_vec();
+    assert_eq!(deltas, vec![3, -4]);
+
+    let mut it = [1, 2, 3].iter().pairwise();
+    assert_eq!(it.len(), 2);
+    assert_eq!(it.next(), Some((&1, &2)));
+    assert_eq!(it.len(), 1);
+    assert_eq!(it.next(), Some((&2, &3)));
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next(), None);
+
+    assert_eq!((0..1).pairwise().size_hint(), (0, Some(0)));
+    assert_eq!((0..1).pairwise().next(), None);
+    assert_eq!((0..0).pairwise().next(), None);
+}
+
+#[test]
+fn sorted_by_cached_key() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let words = ["pear", "fig", "apple", "kiwi", "banana", "plum", "date"];
+    let mut calls = 0;
+    let sorted = words.iter().sorted_by_cached_key(|w| {
+        calls += 1;
+        let mut h = DefaultHasher::new();
+        w.hash(&mut h);
+        (w.len(), h.finish())
+    });
+    let sorted = sorted.collect_vec();
+    assert_eq!(calls, words.len());
+
+    let mut expected = words.iter().collect_vec();
+    expected.sort_by_key(|w| {
+        let mut h = DefaultHasher::new();
+        w.hash(&mut h);
+        (w.len(), h.finish())
+    });
+    assert_eq!(sorted, expected);
+    assert!(sorted.windows(2).all(|w| w[0].len() <= w[1].len()));
+}
+
+#[test]
+fn format() {
+    let data = [0, 1, 2, 3];
+    assert_eq!(format!("{}", data.iter().format(", ")), "0, 1, 2, 3");
+    assert_eq!(format!("{:02}", data.iter().format("")), "00010203");
+    assert_eq!(format!("{}", (0..0).format(", ")), "");
+
+    let ans = data.iter().format_with("|", |elt, f| f(&format_args!("<{}>", elt)));
+    assert_eq!(format!("{}", ans), "<0>|<1>|<2>|<3>");
+
+    let letters = ['a', 'b', 'c'];
+    let numbered = letters.iter().format_with_index(", ", |i, elt, f| {
+        f(&format_args!("{}:{}", i, elt))
+    });
+    assert_eq!(format!("{}", numbered), "0:a, 1:b, 2:c");
+
+    // the separator can be chosen per position by the closure itself
+    let sentence = letters.iter().format_with_index("", |i, elt, f| {
+        if i == 0 { f(elt) }
+        else if i == letters.len() - 1 { try!(f(&" and ")); f(elt) }
+        else { try!(f(&", ")); f(elt) }
+    });
+    assert_eq!(format!("{}", sentence), "a, b and c");
+}
+
+#[test]
+fn group_by_indexed() {
+    let data = ['a', 'a', 'b', 'b', 'b', 'c', 'a'];
+    let groups = data.iter().cloned().group_by_indexed(|&c| c).collect_vec();
+    assert_eq!(groups, vec![(0, 'a', vec!['a', 'a']),
+                            (2, 'b', vec!['b', 'b', 'b']),
+                            (5, 'c', vec!['c']),
+                            (6, 'a', vec!['a'])]);
+
+    // the start index of every run is its position in the source
+    let xs = [1, 3, -2, -2, 1, 0, 0];
+    for (start, key, group) in xs.iter().group_by_indexed(|&&x| x > 0) {
+        assert!(group.iter().all(|&&x| (x > 0) == key));
+        assert_eq!(&xs[start..start + group.len()], &group.into_iter().cloned().collect_vec()[..]);
+    }
+    assert_eq!((0..0).group_by_indexed(|&x| x).next(), None);
+}
+
+#[test]
+fn scan1() {
+    let sums = [1, 2, 3, 4].iter().cloned().scan1(|acc, x| acc + x);
+    assert_eq!(sums.size_hint(), (4, Some(4)));
+    it::assert_equal(sums, vec![1, 3, 6, 10]);
+
+    let maxima = [3, 1, 4, 1, 5, 9, 2, 6].iter().cloned().scan1(|&acc, x| std::cmp::max(acc, x));
+    it::assert_equal(maxima, vec![3, 3, 4, 4, 5, 9, 9, 9]);
+
+    let mut it = (0..0).scan1(|acc, x| acc + x);
+    assert_eq!(it.len(), 0);
+    assert_eq!(it.next(), None);
+    check_size_hint((0..7).scan1(|a, b| a * b));
+}
+
+#[test]
+fn flatten_result_options() {
+    let input: Vec<Result<Option<i32>, &str>> =
+        vec![Ok(Some(1)), Ok(None), Err("x"), Ok(None), Ok(Some(2)), Err("y")];
+    let v = input.iter().cloned().flatten_result_options().collect_vec();
+    assert_eq!(v, vec![Ok(1), Err("x"), Ok(2), Err("y")]);
+
+    let v = input.into_iter().flatten_result_options().rev().collect_vec();
+    assert_eq!(v, vec![Err("y"), Ok(2), Err("x"), Ok(1)]);
+
+    let nones: Vec<Result<Option<i32>, ()>> = vec![Ok(None), Ok(None)];
+    assert_eq!(nones.into_iter().flatten_result_options().next(), None);
+}
+
+#[test]
+fn chunks() {
+    for &(len, n, count) in &[(0, 3, 0), (9, 3, 3), (10, 3, 4), (11, 3, 4),
+                              (5, 1, 5), (5, 7, 1)] {
+        let chunks = (0..len).chunks(n);
+        assert_eq!(chunks.size_hint(), (count, Some(count)));
+        assert_eq!(chunks.len(), count);
+        let v = chunks.collect_vec();
+        assert_eq!(v.len(), count);
+        assert!(v.iter().all(|c| c.len() <= n && !c.is_empty()));
+        it::assert_equal(v.into_iter().flat_map(|c| c), 0..len);
+        check_size_hint((0..len).chunks(n));
+    }
+
+    // inexact bounds are divided separately
+    let evens = (0..10).filter(|x| x % 2 == 0);
+    assert_eq!(evens.chunks(3).size_hint(), (0, Some(4)));
+    assert_eq!((0..).chunks(2).size_hint(), (usize::MAX / 2 + 1, None));
+}
+
+#[test]
+fn merge_into_parts() {
+    let mut m = (0..10).step(2).merge((0..10).step(3));
+    assert_eq!(m.by_ref().take(3).collect_vec(), vec![0, 0, 2]);
+    // 3 was compared against 4 and is still pending on the right
+    let (a, b) = m.into_parts();
+    assert_eq!(a.size_hint(), (3, Some(3)));
+    assert_eq!(b.collect_vec(), vec![3, 6, 9]);
+    assert_eq!(a.collect_vec(), vec![4, 6, 8]);
+
+    // after one side runs out, the other is returned whole
+    let mut m = (0..2).merge(5..8);
+    assert_eq!(m.by_ref().take(3).collect_vec(), vec![0, 1, 5]);
+    let (mut a, b) = m.into_parts();
+    assert_eq!(a.next(), None);
+    it::assert_equal(b, 6..8);
+
+    // untouched merge gives back both inputs
+    let (a, b) = (0..3).merge(1..2).into_parts();
+    it::assert_equal(a.chain(b), vec![0, 1, 2, 1]);
+}
+
+#[test]
+fn filtered_cartesian_product() {
+    let it = (0..4).filtered_cartesian_product(0..4, |a, b| a < b);
+    assert_eq!(it.size_hint(), (0, Some(16)));
+    assert_eq!(it.collect_vec(), vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]);
+
+    let mut it = (0..3).filtered_cartesian_product("ab".chars(), |_, &c| c == 'b');
+    assert_eq!(it.next(), Some((0, 'b')));
+    assert_eq!(it.size_hint(), (0, Some(4)));
+    assert_eq!(it.collect_vec(), vec![(1, 'b'), (2, 'b')]);
+
+    assert_eq!((0..3).filtered_cartesian_product(0..0, |_, _| true).next(), None);
+    assert_eq!((0..0).filtered_cartesian_product(0..3, |_, _| true).size_hint(), (0, Some(0)));
+
+    // the predicate borrows; only accepted pairs clone the left element
+    use std::cell::Cell;
+    struct Counted<'a>(i32, &'a Cell<usize>);
+    impl<'a> Clone for Counted<'a> {
+        fn clone(&self) -> Self {
+            self.1.set(self.1.get() + 1);
+            Counted(self.0, self.1)
+        }
+    }
+    let clones = Cell::new(0);
+    let xs = (0..5).map(|i| Counted(i, &clones));
+    let pairs = xs.filtered_cartesian_product(0..5, |a, b| a.0 + b == 4)
+                  .map(|(a, b)| (a.0, b))
+                  .collect_vec();
+    assert_eq!(pairs, vec![(0, 4), (1, 3), (2, 2), (3, 1), (4, 0)]);
+    assert_eq!(clones.get(), 5);
+}
+
+#[test]
+fn max_min_set_by_key() {
+    let scores = [("a", 3), ("b", 5), ("c", 5), ("d", 1), ("e", 5), ("f", 1)];
+    assert_eq!(it::max_set_by_key(scores.iter().cloned(), |s| s.1),
+               vec![("b", 5), ("c", 5), ("e", 5)]);
+    assert_eq!(it::min_set_by_key(scores.iter().cloned(), |s| s.1),
+               vec![("d", 1), ("f", 1)]);
+
+    // an earlier tie is dropped once a better key shows up
+    assert_eq!(it::max_set_by_key(vec![2, 2, 3, 1, 3], |&x| x), vec![3, 3]);
+    assert_eq!(it::min_set_by_key(vec![2, 2, 1, 3, 1], |&x| x), vec![1, 1]);
+    assert_eq!(it::max_set_by_key(vec![7], |&x| x), vec![7]);
+
+    let mut calls = 0;
+    it::max_set_by_key(0..10, |&x| { calls += 1; x % 3 });
+    assert_eq!(calls, 10);
+
+    assert_eq!(it::max_set_by_key(Vec::<i32>::new(), |&x| x), Vec::<i32>::new());
+    assert_eq!(it::min_set_by_key(Vec::<i32>::new(), |&x| x), Vec::<i32>::new());
+}
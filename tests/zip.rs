@@ -50,6 +50,47 @@ fn test_double_ended_zip_longest() {
     assert_eq!(it.next(), None);
 }
 
+#[test]
+fn zip_pad() {
+    let xs = [1, 2, 3];
+    let ys = ["a", "b"];
+
+    // longer left side: the right side is padded
+    let it = xs.iter().cloned().zip_pad(ys.iter().cloned(), || 0, || "_");
+    assert_eq!(it.size_hint(), (3, Some(3)));
+    assert_eq!(it.len(), 3);
+    assert_eq!(it.collect::<Vec<_>>(), vec![(1, "a"), (2, "b"), (3, "_")]);
+
+    // longer right side: the left side is padded
+    let it = ys.iter().cloned().zip_pad(xs.iter().cloned(), || "_", || 0);
+    assert_eq!(it.collect::<Vec<_>>(), vec![("a", 1), ("b", 2), ("_", 3)]);
+
+    // equal lengths
+    let it = xs.iter().cloned().zip_pad(xs.iter().cloned(), || 0, || 0);
+    assert_eq!(it.collect::<Vec<_>>(), vec![(1, 1), (2, 2), (3, 3)]);
+}
+
+#[test]
+fn zip_pad_fill_calls() {
+    // The fill values are not Clone, and fills are only made for missing slots
+    #[derive(Debug, PartialEq)]
+    struct Fill(usize);
+
+    let mut left_calls = 0;
+    let mut right_calls = 0;
+    {
+        let it = (0..2).map(Fill).zip_pad((0..5).map(Fill),
+                                          || { left_calls += 1; Fill(left_calls + 100) },
+                                          || { right_calls += 1; Fill(right_calls + 200) });
+        let v = it.collect::<Vec<_>>();
+        assert_eq!(v[1], (Fill(1), Fill(1)));
+        assert_eq!(v[4], (Fill(103), Fill(4)));
+        assert_eq!(v.len(), 5);
+    }
+    assert_eq!(left_calls, 3);
+    assert_eq!(right_calls, 0);
+}
+
 
 #[cfg(feature = "unstable")]
 // This function copied from std::iter in rust-lang/rust